@@ -1,19 +1,417 @@
-use iced::widget::{Button, Column, Text, TextInput};
 use iced::alignment::Alignment;
-use iced::{Element, Sandbox, Settings};
-use libguess::{Game, GameTrait, GuessResult};
-use rand::rngs::StdRng;
-use rand::SeedableRng;
-use std::time::{SystemTime, UNIX_EPOCH};
+use iced::widget::{
+    text_input, Button, Checkbox, Column, Row, Scrollable, Slider, Text, TextInput,
+};
+use iced::{executor, time, Application, Command, Element, Settings, Subscription, Theme};
+use libguess::{
+    parse_guess, Game, GameConfig, GameState, GameTrait, GuessInputError, GuessResult, Leaderboard,
+    Profile, ReplayFrame, ScoreEntry,
+};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-pub fn main() -> iced::Result {
+const PLAYER_NAME: &str = "Player";
+const REPLAY_INTERVAL: Duration = Duration::from_millis(800);
+const BOT_INTERVAL_RANGE_MS: std::ops::RangeInclusive<u32> = 100..=2000;
+
+fn main() -> iced::Result {
     GuessUI::run(Settings::default())
 }
 
 struct GuessUI {
     game: Game,
     guess_input: String,
+    /// What happened with the last guess.
     message: String,
+    /// A problem with the typed guess, or a prompt to confirm it, cleared on every change of
+    /// screen.
+    error: Option<String>,
+    leaderboard: Leaderboard,
+    /// Where the leaderboard is saved, or `None` to keep it in memory only.
+    scores_path: Option<PathBuf>,
+    screen: Screen,
+    bot: Bot,
+    /// Games finished without the bot since the app started.
+    session: Profile,
+    /// Whether guesses outside the known range need a second press of Guess before they are
+    /// played.
+    confirm_wasteful: bool,
+    /// A guess outside the known range waiting for the player to confirm it.
+    unconfirmed: Option<u32>,
+}
+
+/// The computer auto-playing the current game for demos.
+struct Bot {
+    playing: bool,
+    interval_ms: u32,
+    /// Whether the bot made any guess in the current game, which keeps it off the leaderboard.
+    assisted: bool,
+}
+
+/// Which screen the app is showing.
+enum Screen {
+    Playing,
+    Leaderboard,
+    Replay(Replay),
+}
+
+/// A replay of a finished game, stepping through a copy of its frames so the game itself is never
+/// touched.
+struct Replay {
+    frames: Vec<ReplayFrame>,
+    position: usize,
+    playing: bool,
+}
+
+impl Replay {
+    fn next(&mut self) {
+        if self.position + 1 < self.frames.len() {
+            self.position += 1;
+        } else {
+            self.playing = false;
+        }
+    }
+
+    fn previous(&mut self) {
+        self.position = self.position.saturating_sub(1);
+    }
+}
+
+/// Returns where the leaderboard is saved: the home directory if there is one, otherwise the
+/// working directory.
+fn scores_path() -> PathBuf {
+    std::env::var_os("HOME")
+        .map(PathBuf::from)
+        .unwrap_or_default()
+        .join(".guess-the-number-scores")
+}
+
+/// Returns a seed for a new game, taken from the clock so each game gets a different one.
+fn new_seed() -> u64 {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    elapsed.as_secs() ^ (u64::from(elapsed.subsec_nanos()) << 32)
+}
+
+/// Returns the id of the guess field, so it can be focused.
+fn guess_input_id() -> text_input::Id {
+    text_input::Id::new("guess")
+}
+
+impl GuessUI {
+    /// Returns whether `value` may be typed into the guess field.
+    ///
+    /// Anything that is not a number no greater than the announced maximum is rejected as it is
+    /// typed, while partial entries below the minimum are still allowed so they can grow into a
+    /// valid guess.
+    fn accepts_input(&self, value: &str) -> bool {
+        value.is_empty()
+            || (value.bytes().all(|b| b.is_ascii_digit())
+                && value
+                    .parse::<u32>()
+                    .is_ok_and(|n| n <= self.game.announced_range().1))
+    }
+
+    /// Adds the just-won game to the leaderboard and saves it if the score made the board, unless
+    /// the bot played any of it.
+    fn record_win(&mut self) {
+        if self.bot.assisted {
+            return;
+        }
+        let entry = self
+            .game
+            .outcome()
+            .and_then(|outcome| ScoreEntry::from_outcome(PLAYER_NAME, &outcome));
+        if let Some(entry) = entry {
+            if self.leaderboard.submit(entry).is_some() {
+                if let Some(path) = &self.scores_path {
+                    if let Err(err) = self.leaderboard.save(path) {
+                        self.message = format!("{} (could not save score: {err})", self.message);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Starts the app with a new game, loading and saving the leaderboard at `scores_path`, or
+    /// keeping it in memory only when that is `None`.
+    fn with_scores_path(scores_path: Option<PathBuf>) -> Self {
+        let leaderboard = scores_path
+            .as_deref()
+            .and_then(|path| Leaderboard::load(path).ok())
+            .unwrap_or_default();
+        Self {
+            game: Game::from_seed(new_seed(), GameConfig::default()),
+            guess_input: String::new(),
+            message: String::new(),
+            error: None,
+            leaderboard,
+            scores_path,
+            screen: Screen::Playing,
+            bot: Bot {
+                playing: false,
+                interval_ms: 700,
+                assisted: false,
+            },
+            session: Profile::new(PLAYER_NAME),
+            confirm_wasteful: true,
+            unconfirmed: None,
+        }
+    }
+
+    /// Switches to `screen`, leaving it clean: any input error or half-typed guess is dropped, and
+    /// the guess field is focused when returning to the game.
+    fn show(&mut self, screen: Screen) -> Command<Message> {
+        self.error = None;
+        self.unconfirmed = None;
+        self.guess_input.clear();
+        let playing = matches!(screen, Screen::Playing);
+        self.screen = screen;
+        if playing {
+            text_input::focus(guess_input_id())
+        } else {
+            Command::none()
+        }
+    }
+
+    /// Returns the current game's seed as shown to the player and copied for sharing, or `None`
+    /// if the game was not made from a seed.
+    fn seed_text(&self) -> Option<String> {
+        self.game.seed.map(|seed| seed.to_string())
+    }
+
+    /// Puts the current game's seed on the clipboard.
+    fn copy_seed(&self) -> Command<Message> {
+        self.seed_text()
+            .map_or_else(Command::none, iced::clipboard::write)
+    }
+
+    /// Shows the current game's seed in a read-only field with a button to copy it.
+    fn seed_view(&self) -> Option<Element<'_, Message>> {
+        let seed = self.seed_text()?;
+        Some(
+            Row::new()
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .push(Text::new("Seed:"))
+                .push(TextInput::new("", &seed).padding(5).width(220))
+                .push(
+                    Button::new(Text::new("Copy"))
+                        .on_press(Message::CopySeedClicked)
+                        .padding(5),
+                )
+                .into(),
+        )
+    }
+
+    /// Returns the session's average attempts per win for the end screen, or "—" before any win.
+    fn session_average(&self) -> String {
+        self.session
+            .average_attempts_per_win()
+            .map_or_else(|| "—".to_string(), |average| format!("{average:.1}"))
+    }
+
+    /// Returns whether the player may give up and see the secret, which is only while the game
+    /// is still being played.
+    fn can_reveal(&self) -> bool {
+        !self.game.is_over()
+    }
+
+    /// Gives up the game and shows the secret, recording it as a loss with no score.
+    fn reveal(&mut self) {
+        if !self.can_reveal() {
+            return;
+        }
+        self.bot.playing = false;
+        let outcome = self.game.forfeit();
+        if !self.bot.assisted {
+            self.session.record(&outcome);
+        }
+        self.unconfirmed = None;
+        self.error = None;
+        self.guess_input.clear();
+        self.message = format!(
+            "You gave up. The secret number was {}. No score for this game.",
+            self.game.secret_number
+        );
+    }
+
+    /// Plays `guess` and shows what happened.
+    fn play_guess(&mut self, guess: u32) {
+        self.error = None;
+        let was_over = self.game.is_over();
+        let result = self.game.play(guess);
+        if !was_over && !self.bot.assisted {
+            if let Some(outcome) = self.game.outcome() {
+                self.session.record(&outcome);
+            }
+        }
+        match result {
+            GuessResult::Correct => {
+                self.message = "Congratulations! You guessed the number!".to_string();
+                if let Some(over) = self.game.over_par().filter(|&over| over > 0) {
+                    self.message = format!("{} {over} over par.", self.message);
+                }
+                if !was_over {
+                    self.record_win();
+                }
+            }
+            GuessResult::TooHigh | GuessResult::TooLow => {
+                let direction = if result == GuessResult::TooHigh {
+                    "high"
+                } else {
+                    "low"
+                };
+                self.message = format!("Too {direction}! Try again.");
+                if self.game.was_closest_yet() {
+                    self.message = format!("{} Getting warmer — closest yet!", self.message);
+                }
+            }
+            GuessResult::Quartile(quarter) => {
+                let (low, high) = self.game.bounds();
+                self.message = format!(
+                    "Wrong! It's in quarter {quarter} of the range, between {low} and {high}."
+                );
+            }
+            GuessResult::NoMoreLives => {
+                self.message = "No more lives left. The secret number was ".to_string()
+                    + &self.game.secret_number.to_string();
+            }
+            GuessResult::Excluded => {
+                self.message = "That number is not in play. Try another.".to_string();
+            }
+            GuessResult::MercyLoss => {
+                self.message = format!(
+                    "Too few lives left to find it, so the game ends here. The secret number was {}.",
+                    self.game.secret_number
+                );
+            }
+            GuessResult::Draw => {
+                self.message =
+                    "You found it, but on your last life, so it's a draw. No score for this game."
+                        .to_string();
+            }
+        }
+    }
+
+    /// Shows the controls for letting the bot play the rest of the game.
+    fn bot_view(&self) -> Element<'_, Message> {
+        Row::new()
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .push(
+                Button::new(Text::new(if self.bot.playing {
+                    "Stop bot"
+                } else {
+                    "Let the bot play"
+                }))
+                .on_press(Message::BotToggled)
+                .padding(10),
+            )
+            .push(
+                Slider::new(
+                    BOT_INTERVAL_RANGE_MS,
+                    self.bot.interval_ms,
+                    Message::BotIntervalChanged,
+                )
+                .step(100u32)
+                .width(150),
+            )
+            .push(Text::new(format!("{} ms per guess", self.bot.interval_ms)))
+            .into()
+    }
+
+    fn leaderboard_view(&self) -> Element<'_, Message> {
+        let mut content = Column::new()
+            .padding(20)
+            .align_items(Alignment::Center)
+            .spacing(10)
+            .push(Text::new("Leaderboard").size(24));
+
+        if self.leaderboard.entries.is_empty() {
+            content =
+                content.push(Text::new("No scores yet. Win a game to get on the board!").size(18));
+        } else {
+            let mut rows = Column::new().spacing(5);
+            for (rank, entry) in self.leaderboard.entries.iter().enumerate() {
+                rows = rows.push(
+                    Row::new()
+                        .spacing(20)
+                        .push(Text::new(format!("{}.", rank + 1)).size(18))
+                        .push(Text::new(&entry.name).size(18))
+                        .push(Text::new(format!("{} points", entry.score)).size(18))
+                        .push(Text::new(format!("{} attempts", entry.attempts)).size(18)),
+                );
+            }
+            content = content.push(Scrollable::new(rows).height(300));
+        }
+
+        content
+            .push(
+                Button::new(Text::new("Back"))
+                    .on_press(Message::BackButtonClicked)
+                    .padding(10),
+            )
+            .into()
+    }
+
+    fn replay_view(replay: &Replay) -> Element<'_, Message> {
+        let frame = &replay.frames[replay.position];
+        let step = match (frame.guess, frame.result) {
+            (Some(guess), Some(result)) => {
+                format!("Guess {}: {} {:?}", replay.position, guess, result)
+            }
+            _ => "Start".to_string(),
+        };
+
+        Column::new()
+            .padding(20)
+            .align_items(Alignment::Center)
+            .spacing(10)
+            .push(
+                Text::new(format!(
+                    "Replay {} of {}",
+                    replay.position,
+                    replay.frames.len() - 1
+                ))
+                .size(24),
+            )
+            .push(Text::new(step).size(18))
+            .push(
+                Text::new(format!(
+                    "The number is between {} and {}",
+                    frame.low, frame.high
+                ))
+                .size(18),
+            )
+            .push(Text::new(format!("Lives: {}", frame.lives)).size(18))
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .push(
+                        Button::new(Text::new("Previous"))
+                            .on_press(Message::ReplayPreviousClicked)
+                            .padding(10),
+                    )
+                    .push(
+                        Button::new(Text::new(if replay.playing { "Pause" } else { "Play" }))
+                            .on_press(Message::ReplayPlayToggled)
+                            .padding(10),
+                    )
+                    .push(
+                        Button::new(Text::new("Next"))
+                            .on_press(Message::ReplayNextClicked)
+                            .padding(10),
+                    )
+                    .push(
+                        Button::new(Text::new("Close"))
+                            .on_press(Message::BackButtonClicked)
+                            .padding(10),
+                    ),
+            )
+            .into()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -21,64 +419,162 @@ enum Message {
     GuessInputChanged(String),
     GuessButtonClicked,
     PlayAgainButtonClicked,
+    LeaderboardButtonClicked,
+    BackButtonClicked,
+    ReplayButtonClicked,
+    ReplayPreviousClicked,
+    ReplayNextClicked,
+    ReplayPlayToggled,
+    ReplayTick,
+    BotToggled,
+    BotIntervalChanged(u32),
+    BotTick,
+    ConfirmWastefulToggled(bool),
+    RevealButtonClicked,
+    CopySeedClicked,
 }
 
-impl Sandbox for GuessUI {
+impl Application for GuessUI {
+    type Executor = executor::Default;
     type Message = Message;
+    type Theme = Theme;
+    type Flags = ();
 
-fn new() -> Self {
-    let seed = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
-    let mut rng = StdRng::seed_from_u64(seed);
-    let game = Game::new(None, None, None, &mut rng);
-    Self {
-        game,
-        guess_input: String::new(),
-        message: String::new(),
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        let ui = Self::with_scores_path(Some(scores_path()));
+        (ui, text_input::focus(guess_input_id()))
     }
-}
 
     fn title(&self) -> String {
         String::from("Guess the Number")
     }
 
-    fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) -> Command<Message> {
         match message {
             Message::GuessInputChanged(value) => {
-                self.guess_input = value;
+                if self.accepts_input(&value) {
+                    self.guess_input = value;
+                }
             }
+            Message::GuessButtonClicked if self.game.is_over() => {}
             Message::GuessButtonClicked => {
-                if let Ok(guess) = self.guess_input.trim().parse() {
-                    let result = self.game.play(guess);
-                    match result {
-                        GuessResult::Correct => {
-                            self.message = "Congratulations! You guessed the number!".to_string();
-                        }
-                        GuessResult::TooHigh => {
-                            self.message = "Too high! Try again.".to_string();
-                        }
-                        GuessResult::TooLow => {
-                            self.message = "Too low! Try again.".to_string();
-                        }
-                        GuessResult::NoMoreLives => {
-                            self.message =
-                                "No more lives left. The secret number was ".to_string()
-                                    + &self.game.secret_number.to_string();
-                        }
+                let (min, max) = self.game.announced_range();
+                match parse_guess(&self.guess_input, min, max) {
+                    Err(GuessInputError::Empty) => {
+                        self.error = Some("Type a number first.".to_string());
+                        return Command::none();
+                    }
+                    Ok(guess)
+                        if self.confirm_wasteful
+                            && self.game.is_wasteful(guess)
+                            && self.unconfirmed != Some(guess) =>
+                    {
+                        self.unconfirmed = Some(guess);
+                        self.error = Some(
+                            "That's outside the known range. Press Guess again to guess anyway."
+                                .to_string(),
+                        );
+                        return Command::none();
+                    }
+                    Ok(guess) => self.play_guess(guess),
+                    Err(GuessInputError::NotANumber) => {
+                        self.error = Some("Please enter a valid number.".to_string());
+                    }
+                    Err(GuessInputError::OutOfRange { min, max }) => {
+                        self.error = Some(format!("Please enter a number from {min} to {max}."));
                     }
-                } else {
-                    self.message = "Please enter a valid number.".to_string();
                 }
+                self.unconfirmed = None;
                 self.guess_input.clear();
             }
             Message::PlayAgainButtonClicked => {
-                let mut rng = StdRng::from_seed(Default::default());
-                self.game = Game::new(None, None, None, &mut rng);
+                let config = GameConfig {
+                    min_num: self.game.min_num,
+                    max_num: self.game.max_num,
+                    lives: self.game.initial_lives,
+                    ..GameConfig::default()
+                };
+                self.game = Game::from_seed(new_seed(), config);
                 self.message.clear();
+                self.bot.playing = false;
+                self.bot.assisted = false;
+                return self.show(Screen::Playing);
+            }
+            Message::LeaderboardButtonClicked => return self.show(Screen::Leaderboard),
+            Message::BackButtonClicked => return self.show(Screen::Playing),
+            Message::ReplayButtonClicked => {
+                let replay = Replay {
+                    frames: self.game.replay_frames(),
+                    position: 0,
+                    playing: false,
+                };
+                return self.show(Screen::Replay(replay));
+            }
+            Message::ReplayPreviousClicked => {
+                if let Screen::Replay(replay) = &mut self.screen {
+                    replay.playing = false;
+                    replay.previous();
+                }
+            }
+            Message::ReplayNextClicked | Message::ReplayTick => {
+                if let Screen::Replay(replay) = &mut self.screen {
+                    replay.next();
+                }
+            }
+            Message::ReplayPlayToggled => {
+                if let Screen::Replay(replay) = &mut self.screen {
+                    if replay.position + 1 == replay.frames.len() {
+                        replay.position = 0;
+                    }
+                    replay.playing = !replay.playing;
+                }
+            }
+            Message::BotToggled => {
+                self.bot.playing = !self.bot.playing && !self.game.is_over();
+            }
+            Message::ConfirmWastefulToggled(confirm) => {
+                self.confirm_wasteful = confirm;
+                self.unconfirmed = None;
+            }
+            Message::RevealButtonClicked => self.reveal(),
+            Message::CopySeedClicked => return self.copy_seed(),
+            Message::BotIntervalChanged(interval) => {
+                self.bot.interval_ms = interval;
+            }
+            Message::BotTick => {
+                if self.bot.playing {
+                    self.bot.assisted = true;
+                    self.play_guess(self.game.suggest_guess());
+                    self.bot.playing = !self.game.is_over();
+                }
             }
         }
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        let replay = match &self.screen {
+            Screen::Replay(replay) if replay.playing => {
+                time::every(REPLAY_INTERVAL).map(|_| Message::ReplayTick)
+            }
+            _ => Subscription::none(),
+        };
+        let bot = if self.bot.playing {
+            time::every(Duration::from_millis(self.bot.interval_ms.into()))
+                .map(|_| Message::BotTick)
+        } else {
+            Subscription::none()
+        };
+        Subscription::batch([replay, bot])
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
+        match &self.screen {
+            Screen::Playing => {}
+            Screen::Leaderboard => return self.leaderboard_view(),
+            Screen::Replay(replay) => return Self::replay_view(replay),
+        }
+
         let mut content = Column::new()
             .padding(20)
             .align_items(Alignment::Center)
@@ -95,29 +591,398 @@ fn new() -> Self {
 
         content = content.push(
             TextInput::new("Guess", &self.guess_input)
+                .id(guess_input_id())
                 .on_input(Message::GuessInputChanged)
                 .padding(10)
                 .size(30),
         );
 
-        content = content.push(
+        let mut buttons = Row::new().spacing(10).push(
             Button::new(Text::new("Guess"))
-                .on_press(Message::GuessButtonClicked)
+                .on_press_maybe((!self.game.is_over()).then_some(Message::GuessButtonClicked))
                 .padding(10),
         );
+        if self.can_reveal() {
+            buttons = buttons.push(
+                Button::new(Text::new("Reveal (−score)"))
+                    .on_press(Message::RevealButtonClicked)
+                    .padding(10),
+            );
+        }
+        content = content.push(buttons);
 
         if !self.message.is_empty() {
             content = content.push(Text::new(&self.message).size(18));
         }
+        if let Some(error) = &self.error {
+            content = content.push(Text::new(error).size(18));
+        }
+
+        content = content.push(
+            Checkbox::new(
+                "Confirm guesses outside the known range",
+                self.confirm_wasteful,
+            )
+            .on_toggle(Message::ConfirmWastefulToggled),
+        );
+
+        if !self.game.is_over() {
+            content = content.push(self.bot_view());
+        }
+        if let Some(seed) = self.seed_view() {
+            content = content.push(seed);
+        }
 
-        if self.game.lives() == 0 {
+        if self.game.state() != GameState::InProgress {
             content = content.push(
-                Button::new(Text::new("Play Again"))
-                    .on_press(Message::PlayAgainButtonClicked)
-                    .padding(10),
+                Text::new(format!(
+                    "Average attempts per win this session: {}",
+                    self.session_average()
+                ))
+                .size(18),
+            );
+            content = content.push(
+                Row::new()
+                    .spacing(10)
+                    .push(
+                        Button::new(Text::new("Play Again"))
+                            .on_press(Message::PlayAgainButtonClicked)
+                            .padding(10),
+                    )
+                    .push(
+                        Button::new(Text::new("Leaderboard"))
+                            .on_press(Message::LeaderboardButtonClicked)
+                            .padding(10),
+                    )
+                    .push(
+                        Button::new(Text::new("Replay"))
+                            .on_press(Message::ReplayButtonClicked)
+                            .padding(10),
+                    ),
             );
         }
 
         content.into()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libguess::optimal_guesses;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn submit(ui: &mut GuessUI, input: &str) {
+        ui.guess_input = input.to_string();
+        let _ = ui.update(Message::GuessButtonClicked);
+    }
+
+    #[test]
+    fn test_submissions() {
+        let mut ui = GuessUI::with_scores_path(None);
+        ui.game.secret_number = 10;
+
+        submit(&mut ui, "");
+        assert_eq!(ui.error.as_deref(), Some("Type a number first."));
+        assert_eq!(ui.game.attempts, 0);
+
+        submit(&mut ui, "   ");
+        assert_eq!(ui.error.as_deref(), Some("Type a number first."));
+        assert_eq!(ui.guess_input, "   ");
+        assert_eq!(ui.game.attempts, 0);
+
+        submit(&mut ui, "abc");
+        assert_eq!(ui.error.as_deref(), Some("Please enter a valid number."));
+        assert!(ui.guess_input.is_empty());
+        assert_eq!(ui.game.attempts, 0);
+
+        submit(&mut ui, "0");
+        assert_eq!(
+            ui.error.as_deref(),
+            Some("Please enter a number from 1 to 20.")
+        );
+        assert_eq!(ui.game.attempts, 0);
+
+        submit(&mut ui, " 4 ");
+        assert_eq!(ui.message, "Too low! Try again.");
+        assert_eq!(ui.error, None);
+        assert!(ui.guess_input.is_empty());
+        assert_eq!(ui.game.attempts, 1);
+
+        submit(&mut ui, "");
+        assert_eq!(ui.error.as_deref(), Some("Type a number first."));
+        assert_eq!(ui.game.attempts, 1);
+    }
+
+    #[test]
+    fn test_guess_input_filter() {
+        let mut ui = GuessUI::with_scores_path(None);
+        assert_eq!(ui.game.announced_range(), (1, 20));
+        for (value, expected) in [
+            ("1", "1"),
+            ("999", "1"),
+            ("1a", "1"),
+            ("", ""),
+            ("20", "20"),
+        ] {
+            let _ = ui.update(Message::GuessInputChanged(value.to_string()));
+            assert_eq!(ui.guess_input, expected);
+        }
+        let _ = ui.update(Message::GuessInputChanged("21".to_string()));
+        assert_eq!(ui.guess_input, "20");
+
+        ui.game.with_announced_range(1, 100).unwrap();
+        let _ = ui.update(Message::GuessInputChanged("99".to_string()));
+        assert_eq!(ui.guess_input, "99");
+        let _ = ui.update(Message::GuessInputChanged("999".to_string()));
+        assert_eq!(ui.guess_input, "99");
+    }
+
+    #[test]
+    fn test_confirm_wasteful() {
+        let mut ui = GuessUI::with_scores_path(None);
+        ui.game.secret_number = 10;
+        submit(&mut ui, "5");
+        assert_eq!(ui.game.attempts, 1);
+
+        submit(&mut ui, "3");
+        assert_eq!(ui.game.attempts, 1);
+        assert_eq!(ui.guess_input, "3");
+        assert!(ui
+            .error
+            .as_deref()
+            .is_some_and(|error| error.starts_with("That's outside the known range.")));
+
+        let _ = ui.update(Message::GuessButtonClicked);
+        assert_eq!(ui.game.attempts, 2);
+        assert!(ui.guess_input.is_empty());
+
+        let _ = ui.update(Message::ConfirmWastefulToggled(false));
+        submit(&mut ui, "2");
+        assert_eq!(ui.game.attempts, 3);
+    }
+
+    #[test]
+    fn test_reveal() {
+        let mut ui = GuessUI::with_scores_path(None);
+        ui.game.secret_number = 10;
+        submit(&mut ui, "5");
+        assert!(ui.can_reveal());
+
+        let _ = ui.update(Message::RevealButtonClicked);
+        assert_eq!(
+            ui.message,
+            "You gave up. The secret number was 10. No score for this game."
+        );
+        assert_eq!(ui.game.state(), GameState::Lost);
+        assert!(!ui.can_reveal());
+        assert_eq!(ui.session.games_played, 1);
+        assert_eq!(ui.session.games_won, 0);
+        assert_eq!(ui.session.best_score, 0);
+
+        let _ = ui.update(Message::RevealButtonClicked);
+        assert_eq!(ui.session.games_played, 1);
+
+        let _ = ui.update(Message::PlayAgainButtonClicked);
+        assert!(ui.can_reveal());
+    }
+
+    #[test]
+    fn test_screen_transitions() {
+        let mut ui = GuessUI::with_scores_path(None);
+        let mut rng = StdRng::from_seed(Default::default());
+        ui.game = Game {
+            secret_number: 30,
+            ..Game::new(Some(1), Some(50), Some(8), &mut rng)
+        };
+        submit(&mut ui, "60");
+        assert!(ui.error.is_some());
+        submit(&mut ui, "30");
+        assert!(ui.game.is_over());
+
+        ui.guess_input = "12".to_string();
+        ui.error = Some("Please enter a valid number.".to_string());
+        let _ = ui.update(Message::LeaderboardButtonClicked);
+        assert!(matches!(ui.screen, Screen::Leaderboard));
+        assert_eq!(ui.error, None);
+        assert!(ui.guess_input.is_empty());
+
+        let _ = ui.update(Message::BackButtonClicked);
+        assert!(matches!(ui.screen, Screen::Playing));
+        assert_eq!(ui.message, "Congratulations! You guessed the number!");
+
+        let _ = ui.update(Message::ReplayButtonClicked);
+        assert!(matches!(ui.screen, Screen::Replay(_)));
+        let _ = ui.update(Message::ReplayNextClicked);
+        let _ = ui.update(Message::BackButtonClicked);
+        assert!(matches!(ui.screen, Screen::Playing));
+
+        ui.error = Some("Type a number first.".to_string());
+        let _ = ui.update(Message::PlayAgainButtonClicked);
+        assert!(matches!(ui.screen, Screen::Playing));
+        assert!(ui.message.is_empty());
+        assert_eq!(ui.error, None);
+        assert_eq!((ui.game.min_num, ui.game.max_num), (1, 50));
+        assert_eq!(ui.game.lives, 8);
+        assert_eq!(ui.game.state(), GameState::InProgress);
+    }
+
+    #[test]
+    fn test_replay_steps_through_history() {
+        let mut ui = GuessUI::with_scores_path(None);
+        let mut rng = StdRng::from_seed(Default::default());
+        ui.game = Game {
+            secret_number: 30,
+            ..Game::new(Some(1), Some(50), Some(8), &mut rng)
+        };
+        for guess in ["25", "40", "35", "30"] {
+            submit(&mut ui, guess);
+        }
+        assert!(ui.game.is_over());
+        let history = ui.game.history.clone();
+        let attempts = ui.game.attempts;
+        let lives = ui.game.lives();
+        let bounds = ui.game.bounds();
+
+        let _ = ui.update(Message::ReplayButtonClicked);
+        let frame = |ui: &GuessUI| match &ui.screen {
+            Screen::Replay(replay) => replay.frames[replay.position],
+            _ => panic!("not on the replay screen"),
+        };
+        let start = frame(&ui);
+        assert_eq!((start.guess, start.result), (None, None));
+        assert_eq!((start.low, start.high, start.lives), (1, 50, 8));
+        for record in &history {
+            let _ = ui.update(Message::ReplayNextClicked);
+            let frame = frame(&ui);
+            assert_eq!(frame.guess, Some(record.guess));
+            assert_eq!(frame.result, Some(record.result));
+            assert_eq!((frame.low, frame.high), (record.low, record.high));
+            assert_eq!(frame.lives, record.lives);
+        }
+        let _ = ui.update(Message::ReplayNextClicked);
+        assert_eq!(frame(&ui).guess, history.last().map(|record| record.guess));
+
+        assert_eq!(ui.game.history, history);
+        assert_eq!(ui.game.attempts, attempts);
+        assert_eq!(ui.game.lives(), lives);
+        assert_eq!(ui.game.bounds(), bounds);
+        assert_eq!(ui.game.state(), GameState::Won);
+    }
+
+    #[test]
+    fn test_bot_plays_to_a_win() {
+        let mut ui = GuessUI::with_scores_path(None);
+        let mut rng = StdRng::from_seed(Default::default());
+        ui.game = Game {
+            secret_number: 37,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+
+        let _ = ui.update(Message::BotToggled);
+        assert!(ui.bot.playing);
+        for _ in 0..100 {
+            if ui.game.is_over() {
+                break;
+            }
+            let _ = ui.update(Message::BotTick);
+        }
+        assert_eq!(ui.game.state(), GameState::Won);
+        assert!(!ui.bot.playing);
+        assert!(ui.bot.assisted);
+        assert!(ui.game.attempts <= optimal_guesses(100));
+
+        let attempts = ui.game.attempts;
+        let _ = ui.update(Message::BotTick);
+        assert_eq!(ui.game.attempts, attempts);
+        let _ = ui.update(Message::BotToggled);
+        assert!(!ui.bot.playing);
+    }
+
+    #[test]
+    fn test_session_average() {
+        let mut ui = GuessUI::with_scores_path(None);
+        assert_eq!(ui.session_average(), "—");
+
+        ui.game.secret_number = 10;
+        submit(&mut ui, "5");
+        assert_eq!(ui.session_average(), "—");
+        submit(&mut ui, "10");
+        assert_eq!(ui.session_average(), "2.0");
+
+        let _ = ui.update(Message::PlayAgainButtonClicked);
+        ui.game.secret_number = 4;
+        submit(&mut ui, "4");
+        assert_eq!(ui.session.games_won, 2);
+        assert_eq!(ui.session_average(), "1.5");
+    }
+
+    #[test]
+    fn test_seed_display() {
+        let mut ui = GuessUI::with_scores_path(None);
+        let seed = ui.game.seed.unwrap();
+        assert_eq!(ui.seed_text(), Some(seed.to_string()));
+        let shared = Game::from_seed(
+            ui.seed_text().unwrap().parse().unwrap(),
+            GameConfig::default(),
+        );
+        assert_eq!(shared.secret_number, ui.game.secret_number);
+        assert_eq!(ui.copy_seed().actions().len(), 1);
+
+        let secret = ui.game.secret_number.to_string();
+        submit(&mut ui, &secret);
+        let _ = ui.update(Message::PlayAgainButtonClicked);
+        let replayed = ui.game.seed.unwrap();
+        assert_ne!(replayed, seed);
+        assert_eq!(ui.seed_text(), Some(replayed.to_string()));
+
+        ui.game.seed = None;
+        assert_eq!(ui.seed_text(), None);
+        assert!(ui.copy_seed().actions().is_empty());
+    }
+
+    #[test]
+    fn test_win_recorded_once() {
+        let path = std::env::temp_dir().join(format!("guess-scores-{}", std::process::id()));
+        let mut ui = GuessUI::with_scores_path(Some(path.clone()));
+        ui.game.secret_number = 10;
+        submit(&mut ui, "10");
+        assert_eq!(ui.leaderboard.entries.len(), 1);
+        assert_eq!(Leaderboard::load(&path).unwrap(), ui.leaderboard);
+
+        submit(&mut ui, "10");
+        assert_eq!(ui.game.attempts, 1);
+        assert_eq!(ui.leaderboard.entries.len(), 1);
+        assert_eq!(ui.session.games_won, 1);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_closest_yet_message() {
+        let mut ui = GuessUI::with_scores_path(None);
+        ui.game.secret_number = 15;
+        submit(&mut ui, "10");
+        assert_eq!(ui.message, "Too low! Try again.");
+        submit(&mut ui, "17");
+        assert_eq!(
+            ui.message,
+            "Too high! Try again. Getting warmer — closest yet!"
+        );
+        submit(&mut ui, "13");
+        assert_eq!(ui.message, "Too low! Try again.");
+    }
+
+    #[test]
+    fn test_over_par_message() {
+        let mut ui = GuessUI::with_scores_path(None);
+        ui.game.secret_number = 8;
+        for guess in 1..=8 {
+            submit(&mut ui, &guess.to_string());
+        }
+        assert_eq!(
+            ui.message,
+            "Congratulations! You guessed the number! 3 over par."
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{Game, GameError, GuessRecord};
+use std::time::Instant;
+
+/// A saved point within a game that [`Game::restore`] can roll back to, such as before a risky
+/// guess.
+///
+/// The secret the game had is kept too, so restoring after a bonus round picks up where the
+/// checkpoint left off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    secret_number: u32,
+    lives: u32,
+    low: u32,
+    high: u32,
+    attempts: u32,
+    won: bool,
+    drawn: bool,
+    bonus_count: u32,
+    find_streak: u32,
+    hint_budget: Option<u32>,
+    lost_at: Vec<Instant>,
+    history: Vec<GuessRecord>,
+    guess_times: Vec<Instant>,
+}
+
+impl Game {
+    /// Captures the lives, bounds, attempts and history so the game can be rolled back to this
+    /// point later with [`Game::restore`].
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            secret_number: self.secret_number,
+            lives: self.lives,
+            low: self.low,
+            high: self.high,
+            attempts: self.attempts,
+            won: self.won,
+            drawn: self.drawn,
+            bonus_count: self.bonus_count,
+            find_streak: self.find_streak,
+            hint_budget: self.hint_budget,
+            lost_at: self.lost_at.clone(),
+            history: self.history.clone(),
+            guess_times: self.guess_times.clone(),
+        }
+    }
+
+    /// Rolls the game back to `checkpoint`, undoing every guess made since it was taken.
+    ///
+    /// The checkpoint should come from this game; the secret is never changed to one it did not
+    /// already have.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::GameOver` if the game has already ended, since its secret may have been
+    /// revealed. The game is left as it is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 700,
+    ///     ..Game::new(Some(1), Some(1000), Some(10), &mut rng)
+    /// };
+    /// game.play(500);
+    /// let saved = game.checkpoint();
+    /// game.play(900);
+    /// game.restore(saved).unwrap();
+    /// assert_eq!(game.bounds(), (501, 1000));
+    /// assert_eq!(game.lives(), 9);
+    /// ```
+    pub fn restore(&mut self, checkpoint: Checkpoint) -> Result<(), GameError> {
+        if self.is_over() {
+            return Err(GameError::GameOver);
+        }
+        self.secret_number = checkpoint.secret_number;
+        self.lives = checkpoint.lives;
+        self.low = checkpoint.low;
+        self.high = checkpoint.high;
+        self.attempts = checkpoint.attempts;
+        self.won = checkpoint.won;
+        self.drawn = checkpoint.drawn;
+        self.bonus_count = checkpoint.bonus_count;
+        self.find_streak = checkpoint.find_streak;
+        self.hint_budget = checkpoint.hint_budget;
+        self.lost_at = checkpoint.lost_at;
+        self.history = checkpoint.history;
+        self.guess_times = checkpoint.guess_times;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Game, GameError, GameState, GameTrait};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_restore() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 333,
+            ..Game::new(Some(1), Some(1000), Some(4), &mut rng)
+        };
+        game.play(500);
+        let saved = game.checkpoint();
+
+        for guess in [100, 200] {
+            game.play(guess);
+        }
+        assert_eq!(game.lives(), 1);
+
+        game.restore(saved.clone()).unwrap();
+        assert_eq!(game.state(), GameState::InProgress);
+        assert_eq!(game.secret_number, 333);
+        assert_eq!(game.lives(), 3);
+        assert_eq!(game.bounds(), (1, 499));
+        assert_eq!(game.attempts, 1);
+        assert_eq!(game.history.len(), 1);
+        assert_eq!(game.checkpoint(), saved);
+
+        game.bonus_mode = true;
+        game.play(333);
+        assert_ne!(game.bounds(), (1, 499));
+        game.restore(saved).unwrap();
+        assert_eq!(game.secret_number, 333);
+        assert!(!game.won);
+        assert_eq!(game.bonus_count, 0);
+    }
+
+    #[test]
+    fn test_restore_refused_once_over() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 333,
+            ..Game::new(Some(1), Some(1000), Some(2), &mut rng)
+        };
+        let saved = game.checkpoint();
+        game.play(100);
+        game.play(200);
+        assert_eq!(game.state(), GameState::Lost);
+        assert_eq!(game.restore(saved), Err(GameError::GameOver));
+        assert_eq!(game.state(), GameState::Lost);
+        assert_eq!(game.attempts, 2);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{Game, GameError};
+use rand::rngs::StdRng;
+
+/// The first byte of every compact payload, bumped whenever the layout changes.
+const COMPACT_VERSION: u8 = 1;
+
+impl Game {
+    /// Encodes the puzzle (range, lives and secret) in as few bytes as possible, for example to
+    /// fit in a QR code.
+    ///
+    /// The payload is a version byte followed by `min_num`, `max_num`, `lives` and the secret as
+    /// LEB128 varints. Progress through the game is not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let game = Game::new(Some(1), Some(100), Some(7), &mut rng);
+    /// let bytes = game.to_compact_bytes();
+    /// assert!(bytes.len() <= 5);
+    /// let shared = Game::from_compact_bytes(&bytes, &mut rng).unwrap();
+    /// assert_eq!(shared.secret_number, game.secret_number);
+    /// ```
+    pub fn to_compact_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![COMPACT_VERSION];
+        for value in [
+            self.min_num,
+            self.max_num,
+            self.initial_lives,
+            self.secret_number,
+        ] {
+            write_varint(&mut bytes, value);
+        }
+        bytes
+    }
+
+    /// Decodes a puzzle written by [`Game::to_compact_bytes`] into a fresh game.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidPayload` if the payload is truncated, has an unknown version or
+    /// describes an impossible game.
+    pub fn from_compact_bytes(bytes: &[u8], rng: &mut StdRng) -> Result<Self, GameError> {
+        let (&version, mut rest) = bytes.split_first().ok_or(GameError::InvalidPayload)?;
+        if version != COMPACT_VERSION {
+            return Err(GameError::InvalidPayload);
+        }
+
+        let mut values = [0; 4];
+        for value in &mut values {
+            *value = read_varint(&mut rest).ok_or(GameError::InvalidPayload)?;
+        }
+        let [min_num, max_num, lives, secret_number] = values;
+        if !rest.is_empty() || min_num > max_num || !(min_num..=max_num).contains(&secret_number) {
+            return Err(GameError::InvalidPayload);
+        }
+
+        Ok(Game {
+            secret_number,
+            ..Game::new(Some(min_num), Some(max_num), Some(lives), rng)
+        })
+    }
+}
+
+/// Appends `value` as an LEB128 varint: seven bits per byte, low bits first, with the top bit set
+/// on every byte but the last.
+fn write_varint(bytes: &mut Vec<u8>, mut value: u32) {
+    while value >= 0x80 {
+        bytes.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+/// Reads an LEB128 varint from the front of `bytes`, advancing past it.
+///
+/// Returns `None` if the bytes run out or the value does not fit in a `u32`.
+fn read_varint(bytes: &mut &[u8]) -> Option<u32> {
+    let mut value: u64 = 0;
+    for shift in (0..u32::BITS).step_by(7) {
+        let (&byte, rest) = bytes.split_first()?;
+        *bytes = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return u32::try_from(value).ok();
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_compact_round_trip() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let game = Game {
+            secret_number: 4_000_000_000,
+            ..Game::new(Some(300), Some(u32::MAX), Some(32), &mut rng)
+        };
+        let bytes = game.to_compact_bytes();
+        assert_eq!(bytes[0], COMPACT_VERSION);
+
+        let decoded = Game::from_compact_bytes(&bytes, &mut rng).unwrap();
+        assert_eq!(decoded.min_num, 300);
+        assert_eq!(decoded.max_num, u32::MAX);
+        assert_eq!(decoded.lives, 32);
+        assert_eq!(decoded.secret_number, 4_000_000_000);
+    }
+
+    #[test]
+    fn test_compact_truncated() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let bytes = Game::new(Some(1), Some(1000), Some(10), &mut rng).to_compact_bytes();
+        for len in 0..bytes.len() {
+            assert_eq!(
+                Game::from_compact_bytes(&bytes[..len], &mut rng).err(),
+                Some(GameError::InvalidPayload)
+            );
+        }
+
+        let mut unknown_version = bytes.clone();
+        unknown_version[0] = 0xff;
+        assert!(Game::from_compact_bytes(&unknown_version, &mut rng).is_err());
+        let overflowing = [COMPACT_VERSION, 0xff, 0xff, 0xff, 0xff, 0x7f, 1, 1, 1];
+        assert!(Game::from_compact_bytes(&overflowing, &mut rng).is_err());
+    }
+}
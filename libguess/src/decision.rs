@@ -0,0 +1,139 @@
+use crate::{midpoint, GameError};
+
+/// One guess in the tree of guesses a perfect player makes, from [`decision_tree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionNode {
+    /// The guess to make: the middle of the numbers still possible.
+    pub guess: u32,
+    /// Where to go when the guess is too high, or `None` if nothing below it is left.
+    pub too_high: Option<Box<DecisionNode>>,
+    /// Where to go when the guess is too low, or `None` if nothing above it is left.
+    pub too_low: Option<Box<DecisionNode>>,
+}
+
+impl DecisionNode {
+    /// The most numbers [`decision_tree`] builds a tree for, one node each.
+    pub const MAX_NUMBERS: u64 = 1 << 16;
+
+    /// Returns how many guesses the longest path takes, counting this one.
+    pub fn depth(&self) -> u32 {
+        let child_depth =
+            |child: &Option<Box<DecisionNode>>| child.as_ref().map_or(0, |node| node.depth());
+        1 + child_depth(&self.too_high).max(child_depth(&self.too_low))
+    }
+
+    /// Returns how many guesses the tree holds, which is one per number in its range.
+    pub fn node_count(&self) -> usize {
+        let child_count =
+            |child: &Option<Box<DecisionNode>>| child.as_ref().map_or(0, |node| node.node_count());
+        1 + child_count(&self.too_high) + child_count(&self.too_low)
+    }
+
+    /// Returns the guesses a perfect player makes to find `secret`, ending with the secret itself,
+    /// or `None` if it is not in the tree.
+    pub fn path_to(&self, secret: u32) -> Option<Vec<u32>> {
+        let mut path = Vec::new();
+        let mut node = self;
+        loop {
+            path.push(node.guess);
+            let next = match secret.cmp(&node.guess) {
+                std::cmp::Ordering::Equal => return Some(path),
+                std::cmp::Ordering::Less => &node.too_high,
+                std::cmp::Ordering::Greater => &node.too_low,
+            };
+            node = next.as_deref()?;
+        }
+    }
+}
+
+/// Builds every guess a perfect player could make over `min..=max`, for teaching binary search.
+///
+/// The root guesses the middle, as [`Game::suggest_guess`](crate::Game::suggest_guess) does, and
+/// each answer leads to the middle of what is left. The tree does not depend on any game, and is
+/// [`optimal_guesses`](crate::optimal_guesses) deep for the size of the range.
+///
+/// # Errors
+///
+/// Returns `GameError::InvalidRange` if `min` is greater than `max`, or
+/// `GameError::RangeTooLargeToEnumerate` if the range holds more than
+/// [`DecisionNode::MAX_NUMBERS`] numbers.
+///
+/// # Examples
+///
+/// ```
+/// use libguess::decision_tree;
+///
+/// let tree = decision_tree(1, 7).unwrap();
+/// assert_eq!(tree.guess, 4);
+/// assert_eq!(tree.too_high.unwrap().guess, 2);
+/// ```
+pub fn decision_tree(min: u32, max: u32) -> Result<DecisionNode, GameError> {
+    if min > max {
+        return Err(GameError::InvalidRange);
+    }
+    if u64::from(max - min) + 1 > DecisionNode::MAX_NUMBERS {
+        return Err(GameError::RangeTooLargeToEnumerate);
+    }
+    Ok(build(min, max))
+}
+
+/// Builds the tree for `low..=high`, which must not be empty.
+fn build(low: u32, high: u32) -> DecisionNode {
+    let guess = midpoint(low, high);
+    DecisionNode {
+        guess,
+        too_high: (guess > low).then(|| Box::new(build(low, guess - 1))),
+        too_low: (guess < high).then(|| Box::new(build(guess + 1, high))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{optimal_guesses, Game, GameTrait, GuessResult};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_depth() {
+        for (min, max, depth) in [(1, 1, 1), (1, 2, 2), (1, 7, 3), (1, 8, 4), (1, 100, 7)] {
+            let tree = decision_tree(min, max).unwrap();
+            assert_eq!(tree.depth(), depth, "{min}..={max}");
+            assert_eq!(tree.depth(), optimal_guesses(u64::from(max - min + 1)));
+            assert_eq!(tree.node_count(), (max - min + 1) as usize);
+        }
+    }
+
+    #[test]
+    fn test_matches_play() {
+        let tree = decision_tree(1, 100).unwrap();
+        let mut rng = StdRng::from_seed(Default::default());
+        for secret in 1..=100 {
+            let mut game = Game {
+                secret_number: secret,
+                ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+            };
+            let mut guesses = Vec::new();
+            loop {
+                let guess = game.suggest_guess();
+                guesses.push(guess);
+                if game.play(guess) == GuessResult::Correct {
+                    break;
+                }
+            }
+            assert_eq!(tree.path_to(secret), Some(guesses));
+        }
+        assert_eq!(tree.path_to(101), None);
+    }
+
+    #[test]
+    fn test_bounded() {
+        assert_eq!(
+            decision_tree(0, u32::MAX),
+            Err(GameError::RangeTooLargeToEnumerate)
+        );
+        assert_eq!(decision_tree(5, 4), Err(GameError::InvalidRange));
+        assert!(decision_tree(1, 1 << 16).is_ok());
+        assert!(decision_tree(0, 1 << 16).is_err());
+    }
+}
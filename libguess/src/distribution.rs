@@ -0,0 +1,79 @@
+use rand::Rng;
+
+/// How the secret number is picked from the range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SecretDistribution {
+    /// Every number in the range is equally likely.
+    Uniform,
+    /// Numbers near the middle of the range are more likely than those near the ends. The secret
+    /// is the average of two uniform draws, rounded down.
+    Triangular,
+}
+
+impl Default for SecretDistribution {
+    fn default() -> Self {
+        SecretDistribution::Uniform
+    }
+}
+
+impl SecretDistribution {
+    /// Draws a number from `min..=max`.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R, min: u32, max: u32) -> u32 {
+        match self {
+            SecretDistribution::Uniform => rng.gen_range(min..=max),
+            SecretDistribution::Triangular => {
+                let size = u64::from(max - min) + 1;
+                let sum = rng.gen_range(0..size) + rng.gen_range(0..size);
+                (u64::from(min) + sum / 2) as u32
+            }
+        }
+    }
+
+    /// Returns the chance that [`SecretDistribution::sample`] draws `value` from `min..=max`.
+    pub fn probability(&self, value: u32, min: u32, max: u32) -> f64 {
+        if !(min..=max).contains(&value) {
+            return 0.0;
+        }
+
+        let size = u64::from(max - min) + 1;
+        match self {
+            SecretDistribution::Uniform => 1.0 / size as f64,
+            SecretDistribution::Triangular => {
+                // Two draws from 0..size add up to `sum` in `min(sum, 2 * size - 2 - sum) + 1`
+                // ways, and both `2 * offset` and `2 * offset + 1` halve down to `offset`.
+                let ways = |sum: u64| {
+                    if sum > 2 * size - 2 {
+                        0
+                    } else {
+                        sum.min(2 * size - 2 - sum) + 1
+                    }
+                };
+                let offset = u64::from(value - min);
+                let pairs = ways(2 * offset) + ways(2 * offset + 1);
+                pairs as f64 / (size as f64 * size as f64)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probabilities_sum_to_one() {
+        for distribution in [SecretDistribution::Uniform, SecretDistribution::Triangular] {
+            for (min, max) in [(1, 1), (1, 2), (1, 10), (5, 104)] {
+                let total: f64 = (min..=max)
+                    .map(|value| distribution.probability(value, min, max))
+                    .sum();
+                assert!((total - 1.0).abs() < 1e-9);
+            }
+        }
+        assert!(
+            SecretDistribution::Triangular.probability(5, 1, 10)
+                > SecretDistribution::Triangular.probability(1, 1, 10)
+        );
+    }
+}
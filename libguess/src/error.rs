@@ -0,0 +1,73 @@
+use std::fmt;
+
+/// Represents a game that could not be set up as requested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameError {
+    /// The minimum is greater than the maximum.
+    InvalidRange,
+    /// Every number in the range was excluded, leaving nothing to pick the secret from.
+    AllExcluded,
+    /// A head start's bounds would leave out the secret number.
+    HeadStartExcludesSecret,
+    /// An announced range would leave out part of the range the secret is drawn from.
+    AnnouncedRangeTooNarrow,
+    /// A compact game payload was truncated, malformed or from an unknown version.
+    InvalidPayload,
+    /// A shared game link is missing a setting, or has one that is repeated or malformed.
+    InvalidUrl,
+    /// The range holds too many numbers to keep something for each of them.
+    RangeTooLargeToEnumerate,
+    /// The game is already over, so it can no longer be changed.
+    GameOver,
+    /// The game has a setting that a shared game link cannot hold.
+    Unshareable,
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::InvalidRange => write!(f, "the minimum is greater than the maximum"),
+            GameError::AllExcluded => write!(f, "every number in the range is excluded"),
+            GameError::HeadStartExcludesSecret => {
+                write!(f, "the head start does not contain the secret number")
+            }
+            GameError::AnnouncedRangeTooNarrow => {
+                write!(f, "the announced range does not cover the secret's range")
+            }
+            GameError::InvalidPayload => write!(f, "the game payload is invalid"),
+            GameError::InvalidUrl => write!(f, "the game link is invalid"),
+            GameError::RangeTooLargeToEnumerate => {
+                write!(f, "the range is too large to go through number by number")
+            }
+            GameError::GameOver => write!(f, "the game is already over"),
+            GameError::Unshareable => write!(f, "the game has a setting a link cannot hold"),
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+/// Represents typed input that could not be used as a guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuessInputError {
+    /// Nothing but whitespace was entered.
+    Empty,
+    /// The input is not a whole number.
+    NotANumber,
+    /// The number is outside the range the secret is drawn from.
+    OutOfRange { min: u32, max: u32 },
+}
+
+impl fmt::Display for GuessInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuessInputError::Empty => write!(f, "no number was entered"),
+            GuessInputError::NotANumber => write!(f, "the input is not a whole number"),
+            GuessInputError::OutOfRange { min, max } => {
+                write!(f, "the guess must be from {min} to {max}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GuessInputError {}
@@ -0,0 +1,167 @@
+use crate::{Game, GameState, GuessResult};
+use std::fmt::Write;
+
+impl Game {
+    /// Writes what the player is allowed to see as a JSON object, without needing the `serde`
+    /// feature.
+    ///
+    /// The object holds the fields of [`Game::public_state`] and the guesses in the history. The
+    /// secret is only included, as `secret`, once the game is over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 7,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// game.play(3);
+    /// assert_eq!(
+    ///     game.to_json(),
+    ///     r#"{"min_num":1,"max_num":10,"low":4,"high":10,"lives":4,"attempts":1,"state":"in_progress","guesses":[{"guess":3,"result":"too_low"}]}"#
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let state = self.public_state();
+        let mut json = String::from("{");
+        for (key, value) in [
+            ("min_num", state.min_num),
+            ("max_num", state.max_num),
+            ("low", state.low),
+            ("high", state.high),
+            ("lives", state.lives),
+            ("attempts", state.attempts),
+        ] {
+            let _ = write!(json, "{}:{value},", json_string(key));
+        }
+        let state_name = match state.state {
+            GameState::InProgress => "in_progress",
+            GameState::Won => "won",
+            GameState::Lost => "lost",
+            GameState::Drawn => "drawn",
+        };
+        let _ = write!(json, "\"state\":{},\"guesses\":[", json_string(state_name));
+        for (i, record) in self.history.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            let _ = write!(
+                json,
+                "{{\"guess\":{},\"result\":{}",
+                record.guess,
+                json_string(result_name(record.result))
+            );
+            if let GuessResult::Quartile(quarter) = record.result {
+                let _ = write!(json, ",\"quartile\":{quarter}");
+            }
+            json.push('}');
+        }
+        json.push(']');
+        if let Some(secret) = self.reveal() {
+            let _ = write!(json, ",\"secret\":{secret}");
+        }
+        json.push('}');
+        json
+    }
+}
+
+/// Returns the name a result is written as in JSON.
+fn result_name(result: GuessResult) -> &'static str {
+    match result {
+        GuessResult::Correct => "correct",
+        GuessResult::TooHigh => "too_high",
+        GuessResult::TooLow => "too_low",
+        GuessResult::NoMoreLives => "no_more_lives",
+        GuessResult::Excluded => "excluded",
+        GuessResult::Draw => "draw",
+        GuessResult::MercyLoss => "mercy_loss",
+        GuessResult::Quartile(_) => "quartile",
+    }
+}
+
+/// Quotes `value` as a JSON string, escaping quotes, backslashes and control characters.
+fn json_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", u32::from(c));
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GameTrait;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("plain"), r#""plain""#);
+        assert_eq!(json_string("a \"b\" \\ c"), r#""a \"b\" \\ c""#);
+        assert_eq!(json_string("line\nbreak\u{1}"), r#""line\nbreak\u0001""#);
+    }
+
+    #[test]
+    fn test_to_json_hides_secret() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 123,
+            ..Game::new(Some(1), Some(200), Some(3), &mut rng)
+        };
+        game.play(100);
+        game.play(150);
+        let json = game.to_json();
+        assert!(json.starts_with('{') && json.ends_with('}'));
+        assert!(!json.contains("secret"));
+        assert!(!json.contains("123"));
+        assert!(json.contains(r#""state":"in_progress""#));
+        let guesses =
+            r#""guesses":[{"guess":100,"result":"too_low"},{"guess":150,"result":"too_high"}]"#;
+        assert!(json.contains(guesses));
+
+        game.play(199);
+        let json = game.to_json();
+        assert!(json.contains(r#""state":"lost""#));
+        assert!(json.ends_with(r#","secret":123}"#));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_to_json_parses() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 12,
+            ..Game::new(Some(1), Some(20), Some(5), &mut rng)
+        };
+        let value: serde_json::Value = serde_json::from_str(&game.to_json()).unwrap();
+        assert_eq!(value["guesses"], serde_json::json!([]));
+        assert!(value.get("secret").is_none());
+
+        game.play(5);
+        game.play(12);
+        let value: serde_json::Value = serde_json::from_str(&game.to_json()).unwrap();
+        assert_eq!(value["low"], 12);
+        assert_eq!(value["lives"], 4);
+        assert_eq!(value["state"], "won");
+        assert_eq!(value["guesses"][1]["result"], "correct");
+        assert_eq!(value["secret"], 12);
+    }
+}
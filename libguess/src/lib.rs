@@ -1,19 +1,132 @@
 use rand::prelude::*;
 use std::cmp::Ordering;
+use std::time::{Duration, Instant};
+
+#[macro_use]
+mod logging;
+mod checkpoint;
+mod compact;
+mod decision;
+mod distribution;
+mod error;
+mod json;
+mod link;
+mod noisy;
+mod profile;
+mod report;
+mod reverse;
+mod rng;
+mod scores;
+mod session;
+mod shared;
+mod strategy;
+mod team;
+mod tracker;
+mod trainer;
+
+pub use checkpoint::Checkpoint;
+pub use decision::{decision_tree, DecisionNode};
+pub use distribution::SecretDistribution;
+pub use error::{GameError, GuessInputError};
+pub use noisy::NoisyReasoner;
+pub use profile::Profile;
+pub use reverse::ComputerGuesser;
+pub use rng::RngKind;
+pub use scores::{Leaderboard, ScoreEntry};
+pub use session::Session;
+pub use shared::SharedGame;
+pub use strategy::{CompareStrategy, HighLow, Reversed};
+pub use team::TeamGame;
+pub use tracker::Tracker;
+pub use trainer::recommend_next_config;
 
 /// Represents a number guessing game.
+///
+/// With the `serde` feature the game can be saved and loaded. The random number generator is not
+/// saved; a loaded game gets a freshly seeded one, and loses any guess timestamps and lives still
+/// waiting to regenerate.
+/// The comparison strategy is not saved either, and a loaded game uses [`HighLow`]. Nor is
+/// `secret_rng`, so a loaded game draws later secrets from `rng`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Game {
     pub min_num: u32,
     pub max_num: u32,
     pub lives: u32,
+    #[cfg_attr(feature = "serde", serde(skip, default = "StdRng::from_entropy"))]
     pub rng: StdRng,
     pub secret_number: u32,
+    pub low: u32,
+    pub high: u32,
+    pub attempts: u32,
+    pub won: bool,
+    /// Whether the secret was found on the last life of a game that requires a spare one.
+    pub drawn: bool,
+    pub bonus_mode: bool,
+    pub bonus_count: u32,
+    pub initial_lives: u32,
+    pub regen_cooldown: Option<Duration>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub lost_at: Vec<Instant>,
+    pub history: Vec<GuessRecord>,
+    pub excluded: Vec<u32>,
+    pub seed: Option<u64>,
+    #[cfg_attr(feature = "serde", serde(skip, default = "default_strategy"))]
+    pub strategy: Box<dyn CompareStrategy + Send>,
+    pub distribution: SecretDistribution,
+    pub avoid_obvious: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub guess_times: Vec<Instant>,
+    pub lie_chance: f64,
+    pub free_first_guess: bool,
+    pub tutorial: bool,
+    pub free_hints: bool,
+    pub announced_range: Option<(u32, u32)>,
+    /// Keeps only the most recent guesses in `history` (and their timestamps) when set. The
+    /// bounds do not depend on the history, so they stay correct.
+    pub history_cap: Option<usize>,
+    pub find_streak: u32,
+    /// The lives [`Game::apply_guess_str`] takes for input outside the announced range, which it
+    /// otherwise rejects for free.
+    pub out_of_range_penalty: u32,
+    /// The highest score [`Game::score`] gives, if any.
+    pub max_score: Option<u32>,
+    /// Makes finding the secret on the last life a draw rather than a win.
+    pub require_spare_life: bool,
+    /// Ends the game early with `GuessResult::MercyLoss` once it is hopeless.
+    pub mercy_rule: bool,
+    /// The hints left when hints come from a budget of their own rather than from lives.
+    pub hint_budget: Option<u32>,
+    /// Answers wrong guesses with `GuessResult::Quartile` instead of high or low.
+    pub quartile_hints: bool,
+    /// Whether the game began with the automatic first guess of [`GameConfig::cold_start`].
+    pub cold_start: bool,
+    /// Whether that automatic first guess cost a life and counted as an attempt.
+    pub cold_start_counts: bool,
+    /// The `hint_budget` the game started with, before any hints were taken.
+    pub initial_hint_budget: Option<u32>,
+    /// The kind of generator `secret_rng` was made from.
+    pub rng_kind: RngKind,
+    /// Draws the secrets instead of `rng` when set, from the generator chosen by
+    /// [`GameConfig::rng_kind`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub secret_rng: Option<Box<dyn RngCore + Send>>,
 }
 
 impl Game {
     pub const MIN_NUM: u32 = 1;
     pub const MAX_NUM: u32 = 20;
     pub const LIVES: u32 = 10;
+    pub const BONUS_POINTS: u32 = 50;
+    /// How far, as a fraction of half the range, the average guess must sit from the midpoint
+    /// before [`Game::bias_toward`] calls it a bias.
+    pub const BIAS_THRESHOLD: f64 = 0.1;
+    /// How long a game can take before [`Game::timed_score`] halves its score.
+    pub const TIMED_SCORE_HALF_LIFE: Duration = Duration::from_secs(30);
+    /// How many equal slices of the range [`Game::exploration_coverage`] looks for guesses in.
+    pub const EXPLORATION_BUCKETS: u64 = 10;
+    /// The chance of a perfect player still finding the secret below which the mercy rule calls a
+    /// game hopeless.
+    pub const MERCY_CHANCE: f64 = 0.1;
 
     /// Creates a new instance of the `Game` struct.
     ///
@@ -47,177 +160,5196 @@ impl Game {
     /// assert_eq!(default_game.max_num(), Game::MAX_NUM);
     /// assert_eq!(default_game.lives(), Game::LIVES);
     /// ```
-    pub fn new(min_num: Option<u32>, max_num: Option<u32>, lives: Option<u32>, rng: &mut StdRng) -> Self {
-        let secret_number = rng.gen_range(min_num.unwrap_or(Self::MIN_NUM)..=max_num.unwrap_or(Self::MAX_NUM));
+    pub fn new(
+        min_num: Option<u32>,
+        max_num: Option<u32>,
+        lives: Option<u32>,
+        rng: &mut StdRng,
+    ) -> Self {
+        let min_num = min_num.unwrap_or(Self::MIN_NUM);
+        let max_num = max_num.unwrap_or(Self::MAX_NUM);
+        let secret_number = draw_secret(rng, min_num, max_num, &[]);
+        let lives = lives.unwrap_or(Self::LIVES);
+        log_debug!(
+            "new game: secret drawn from {}..={}, {} lives",
+            min_num,
+            max_num,
+            lives
+        );
+        log_trace!("secret number is {}", secret_number);
         Game {
-            min_num: min_num.unwrap_or(Self::MIN_NUM),
-            max_num: max_num.unwrap_or(Self::MAX_NUM),
-            lives: lives.unwrap_or(Self::LIVES),
+            min_num,
+            max_num,
+            lives,
             rng: rng.clone(),
             secret_number,
+            low: min_num,
+            high: max_num,
+            attempts: 0,
+            won: false,
+            drawn: false,
+            bonus_mode: false,
+            bonus_count: 0,
+            initial_lives: lives,
+            regen_cooldown: None,
+            lost_at: Vec::new(),
+            history: Vec::new(),
+            excluded: Vec::new(),
+            seed: None,
+            strategy: default_strategy(),
+            distribution: SecretDistribution::Uniform,
+            avoid_obvious: false,
+            guess_times: Vec::new(),
+            lie_chance: 0.0,
+            free_first_guess: false,
+            tutorial: false,
+            free_hints: false,
+            announced_range: None,
+            history_cap: None,
+            find_streak: 0,
+            out_of_range_penalty: 0,
+            max_score: None,
+            require_spare_life: false,
+            mercy_rule: false,
+            hint_budget: None,
+            quartile_hints: false,
+            cold_start: false,
+            cold_start_counts: false,
+            initial_hint_budget: None,
+            rng_kind: RngKind::Std,
+            secret_rng: None,
         }
     }
 
-    /// Returns a reference to the random number generator.
-    pub fn rng(&self) -> &StdRng {
-        &self.rng
+    /// Creates a game whose secret number is never one of the `exclude`d values.
+    ///
+    /// Guessing an excluded number is rejected with `GuessResult::Excluded` and costs nothing.
+    /// Defaults for `min_num`, `max_num` and `lives` are the same as for [`Game::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidRange` if `min_num` is greater than `max_num`, or
+    /// `GameError::AllExcluded` if every number in the range is excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait, GuessResult};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game::new_excluding(Some(1), Some(30), None, &[10, 20, 30], &mut rng).unwrap();
+    /// assert_eq!(game.play(10), GuessResult::Excluded);
+    /// assert_eq!(game.lives(), Game::LIVES);
+    /// ```
+    pub fn new_excluding(
+        min_num: Option<u32>,
+        max_num: Option<u32>,
+        lives: Option<u32>,
+        exclude: &[u32],
+        rng: &mut StdRng,
+    ) -> Result<Self, GameError> {
+        let min = min_num.unwrap_or(Self::MIN_NUM);
+        let max = max_num.unwrap_or(Self::MAX_NUM);
+        if min > max {
+            return Err(GameError::InvalidRange);
+        }
+
+        let mut excluded: Vec<u32> = exclude
+            .iter()
+            .copied()
+            .filter(|n| (min..=max).contains(n))
+            .collect();
+        excluded.sort_unstable();
+        excluded.dedup();
+        if excluded.len() as u64 == u64::from(max - min) + 1 {
+            return Err(GameError::AllExcluded);
+        }
+
+        let mut game = Self::new(Some(min), Some(max), lives, rng);
+        game.secret_number = draw_secret(&mut game.rng, min, max, &excluded);
+        log_trace!("secret number is {}", game.secret_number);
+        game.excluded = excluded;
+        Ok(game)
     }
-}
 
-/// Represents the result of a single guess.
-#[derive(Debug, PartialEq)]
-pub enum GuessResult {
-    Correct,
-    TooHigh,
-    TooLow,
-    NoMoreLives,
-}
+    /// Creates a game whose secret number is drawn using `distribution` rather than uniformly.
+    ///
+    /// Defaults for `min_num`, `max_num` and `lives` are the same as for [`Game::new`]. Bonus
+    /// rounds draw from the same distribution.
+    pub fn new_with_distribution(
+        min_num: Option<u32>,
+        max_num: Option<u32>,
+        lives: Option<u32>,
+        distribution: SecretDistribution,
+        rng: &mut StdRng,
+    ) -> Self {
+        let mut game = Self::new(min_num, max_num, lives, rng);
+        game.secret_number = distribution.sample(&mut game.rng, game.min_num, game.max_num);
+        log_trace!("secret number is {}", game.secret_number);
+        game.distribution = distribution;
+        game
+    }
 
-/// Defines the behavior of the number guessing game.
-pub trait GameTrait {
-    /// Runs the number guessing game.
+    /// Creates a game from a seed, so the same seed and config always give the same secret.
     ///
-    /// # Arguments
+    /// The seed is kept in `seed` so the game can be shared.
     ///
-    /// * `guess`: The player's guess.
+    /// # Examples
     ///
-    /// # Returns
+    /// ```
+    /// use libguess::{Game, GameConfig};
     ///
-    /// A `GuessResult` indicating the result of the guess.
+    /// let a = Game::from_seed(42, GameConfig::default());
+    /// let b = Game::from_seed(42, GameConfig::default());
+    /// assert_eq!(a.secret_number, b.secret_number);
+    /// assert_eq!(a.seed, Some(42));
+    /// ```
+    pub fn from_seed(seed: u64, config: GameConfig) -> Self {
+        let mut game = Self::from_config(config, &mut StdRng::seed_from_u64(seed));
+        game.seed = Some(seed);
+        game
+    }
+
+    /// Creates `count` puzzles for a tournament, so every participant faces the same secrets in the
+    /// same order.
+    ///
+    /// Each puzzle gets its own seed, drawn in turn from a generator seeded with `seed`, and is
+    /// made with [`Game::from_seed`]. A longer tournament from the same seed starts with the same
+    /// puzzles as a shorter one.
     ///
     /// # Examples
     ///
     /// ```
-    /// use libguess::{Game, GameTrait, GuessResult};
+    /// use libguess::{Game, GameConfig};
+    ///
+    /// let a = Game::tournament(7, 5, GameConfig::default());
+    /// let b = Game::tournament(7, 5, GameConfig::default());
+    /// assert_eq!(a.len(), 5);
+    /// assert!(a.iter().zip(&b).all(|(a, b)| a.secret_number == b.secret_number));
+    /// ```
+    pub fn tournament(seed: u64, count: usize, config: GameConfig) -> Vec<Self> {
+        let mut seeds = StdRng::seed_from_u64(seed);
+        (0..count)
+            .map(|_| Self::from_seed(seeds.gen(), config))
+            .collect()
+    }
+
+    /// Creates a game from a [`GameConfig`], playing the automatic first guess if `cold_start` is
+    /// set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameConfig};
     /// use rand::SeedableRng;
     /// use rand::rngs::StdRng;
     ///
-    /// let mut rng = StdRng::from_seed(Default::default());
-    /// let mut game = Game {
+    /// let config = GameConfig {
     ///     min_num: 1,
-    ///     max_num: 10,
-    ///     lives: 3,
-    ///     rng: rng.clone(),
-    ///     secret_number: 7,
+    ///     max_num: 100,
+    ///     cold_start: true,
+    ///     ..GameConfig::default()
     /// };
-    /// assert_eq!(game.play(5), GuessResult::TooLow);
-    /// assert_eq!(game.play(7), GuessResult::Correct);
+    /// let game = Game::from_config(config, &mut StdRng::from_seed(Default::default()));
+    /// assert_eq!(game.history[0].guess, 50);
+    /// assert_eq!(game.attempts, 0);
     /// ```
-    fn play(&mut self, guess: u32) -> GuessResult;
-
-    /// Returns the minimum value for the secret number.
-    fn min_num(&self) -> u32;
+    pub fn from_config(config: GameConfig, rng: &mut StdRng) -> Self {
+        let mut game = Self::new(
+            Some(config.min_num),
+            Some(config.max_num),
+            Some(config.lives),
+            rng,
+        );
+        game.avoid_obvious = config.avoid_obvious && game.max_num - game.min_num > 2;
+        game.rng_kind = config.rng_kind;
+        if config.rng_kind != RngKind::Std {
+            game.secret_rng = Some(config.rng_kind.seeded(game.rng.gen()));
+        }
+        if game.avoid_obvious || game.secret_rng.is_some() {
+            game.secret_number = game.draw_round_secret();
+            log_trace!("secret number is {}", game.secret_number);
+        }
+        game.lie_chance = config.lie_chance.clamp(0.0, 1.0);
+        game.free_first_guess = config.free_first_guess;
+        game.tutorial = config.tutorial;
+        game.free_hints = config.free_hints;
+        game.history_cap = config.history_cap;
+        game.out_of_range_penalty = config.out_of_range_penalty;
+        game.max_score = config.max_score;
+        game.require_spare_life = config.require_spare_life;
+        game.mercy_rule = config.mercy_rule;
+        game.hint_budget = config.hint_budget;
+        game.initial_hint_budget = config.hint_budget;
+        game.quartile_hints = config.quartile_hints;
+        game.cold_start_counts = config.cold_start_counts;
+        if config.cold_start {
+            game.play_cold_start();
+        }
+        game
+    }
 
-    /// Returns the maximum value for the secret number.
-    fn max_num(&self) -> u32;
+    /// Plays the suggested first guess of a cold start, giving back the attempt and life it took
+    /// unless `cold_start_counts` is set.
+    pub(crate) fn play_cold_start(&mut self) {
+        self.cold_start = true;
+        let lives = self.lives;
+        self.play(self.suggest_guess());
+        if !self.cold_start_counts {
+            self.attempts -= 1;
+            self.lives = lives;
+            self.lost_at.clear();
+            if let Some(record) = self.history.last_mut() {
+                record.lives = lives;
+            }
+        }
+    }
 
-    /// Returns the number of lives the player has.
-    fn lives(&self) -> u32;
-}
+    /// Creates a game seeded from a passphrase, so players can share a memorable word such as
+    /// "dragon" and all face the same secret.
+    ///
+    /// The phrase is hashed with 64-bit FNV-1a rather than the standard library's hasher, whose
+    /// output is randomised per process and may change between Rust releases. FNV-1a is fixed, so a
+    /// phrase maps to the same seed on every run and platform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameConfig};
+    ///
+    /// let a = Game::from_seed_str("dragon", GameConfig::default());
+    /// let b = Game::from_seed_str("dragon", GameConfig::default());
+    /// assert_eq!(a.secret_number, b.secret_number);
+    /// ```
+    pub fn from_seed_str(phrase: &str, config: GameConfig) -> Self {
+        Self::from_seed(fnv1a(phrase.as_bytes()), config)
+    }
 
-impl GameTrait for Game {
-    fn play(&mut self, guess: u32) -> GuessResult {
-        if self.lives() == 0 {
-            return GuessResult::NoMoreLives;
+    /// Gives the player a head start by narrowing the starting bounds to `low..=high`.
+    ///
+    /// Only the bounds the player sees (and that hints work from) change; the secret stays as it
+    /// was drawn from the full range. The head start can only narrow the bounds, so called
+    /// mid-game it never gives back ground the player's guesses have already ruled out.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidRange` if `low..=high` is empty or not inside the announced
+    /// range, or `GameError::HeadStartExcludesSecret` if it does not contain the secret.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameError};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 40,
+    ///     ..Game::new(Some(1), Some(100), Some(7), &mut rng)
+    /// };
+    /// assert_eq!(game.with_head_start(60, 90), Err(GameError::HeadStartExcludesSecret));
+    /// assert_eq!(game.with_head_start(25, 50), Ok(()));
+    /// assert_eq!(game.bounds(), (25, 50));
+    /// ```
+    pub fn with_head_start(&mut self, low: u32, high: u32) -> Result<(), GameError> {
+        let (min_num, max_num) = self.announced_range();
+        if low > high || low < min_num || high > max_num {
+            return Err(GameError::InvalidRange);
+        }
+        let (low, high) = (low.max(self.low), high.min(self.high));
+        if !(low..=high).contains(&self.secret_number) {
+            return Err(GameError::HeadStartExcludesSecret);
         }
+        self.low = low;
+        self.high = high;
+        Ok(())
+    }
 
-        let result = compare(guess, self.secret_number);
-        if result != GuessResult::Correct {
-            self.lives -= 1;
+    /// Tells the player a looser range, `low..=high`, than the one the secret is really drawn from,
+    /// for a harder game.
+    ///
+    /// The bounds, hints and everything else the player sees start from the announced range; the
+    /// secret, including in bonus rounds, still comes from `min_num..=max_num`. Call this before
+    /// the first guess.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidRange` if `low` is greater than `high`, or
+    /// `GameError::AnnouncedRangeTooNarrow` if `low..=high` does not cover
+    /// `min_num..=max_num`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameError};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game::new(Some(20), Some(80), Some(7), &mut rng);
+    /// assert_eq!(game.with_announced_range(30, 100), Err(GameError::AnnouncedRangeTooNarrow));
+    /// assert_eq!(game.with_announced_range(1, 100), Ok(()));
+    /// assert_eq!(game.bounds(), (1, 100));
+    /// assert_eq!(game.public_state().min_num, 1);
+    /// ```
+    pub fn with_announced_range(&mut self, low: u32, high: u32) -> Result<(), GameError> {
+        if low > high {
+            return Err(GameError::InvalidRange);
         }
-        result
+        if low > self.min_num || high < self.max_num {
+            return Err(GameError::AnnouncedRangeTooNarrow);
+        }
+        self.announced_range = Some((low, high));
+        self.low = low;
+        self.high = high;
+        Ok(())
     }
 
-    fn min_num(&self) -> u32 {
-        self.min_num
+    /// Returns the range the player is told the secret lies in, as `(low, high)`.
+    ///
+    /// This is `(min_num, max_num)` unless a looser range was set with
+    /// [`Game::with_announced_range`].
+    pub fn announced_range(&self) -> (u32, u32) {
+        self.announced_range.unwrap_or((self.min_num, self.max_num))
     }
 
-    fn max_num(&self) -> u32 {
-        self.max_num
+    /// Reads a game saved as JSON by [`Game::to_writer`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails or the data is not a valid saved game.
+    #[cfg(feature = "serde")]
+    pub fn from_reader(reader: impl std::io::Read) -> serde_json::Result<Self> {
+        serde_json::from_reader(reader)
     }
 
-    fn lives(&self) -> u32 {
-        self.lives
+    /// Saves the game as JSON, for example to a file or socket.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    #[cfg(feature = "serde")]
+    pub fn to_writer(&self, writer: impl std::io::Write) -> serde_json::Result<()> {
+        serde_json::to_writer(writer, self)
     }
-}
 
-/// Performs the comparison between a guess and the secret number.
-///
-/// # Arguments
-///
-/// * `guess`: The player's guess.
-/// * `secret`: The secret number to compare against.
-///
-/// # Returns
-///
-/// A `GuessResult` indicating whether the guess is correct, too high, or too low.
-///
-/// # Examples
-///
-/// ```
-/// use libguess::compare;
-/// use libguess::GuessResult;
-///
-/// assert_eq!(compare(5, 5), GuessResult::Correct);
-/// assert_eq!(compare(4, 5), GuessResult::TooLow);
-/// assert_eq!(compare(6, 5), GuessResult::TooHigh);
-/// ```
-pub fn compare(guess: u32, secret: u32) -> GuessResult {
-    match guess.cmp(&secret) {
-        Ordering::Equal => GuessResult::Correct,
-        Ordering::Less => GuessResult::TooLow,
-        Ordering::Greater => GuessResult::TooHigh,
+    /// Returns a reference to the random number generator.
+    pub fn rng(&self) -> &StdRng {
+        &self.rng
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Returns a mutable reference to the random number generator, so callers can draw related
+    /// randomness from the game's single stream instead of creating a second generator.
+    ///
+    /// Every value drawn here moves the stream on, changing the numbers the game draws later (such
+    /// as bonus round secrets). A seeded game only replays the same way if callers make the same
+    /// draws at the same points.
+    pub fn rng_mut(&mut self) -> &mut StdRng {
+        &mut self.rng
+    }
 
-    #[test]
-    fn test_new_game() {
-        let mut rng = StdRng::from_seed(Default::default());
-        let game = Game::new(Some(1), Some(10), Some(5), &mut rng);
-        assert_eq!(game.min_num(), 1);
-        assert_eq!(game.max_num(), 10);
-        assert_eq!(game.lives(), 5);
+    /// Returns the range the secret number is still known to lie in, as `(low, high)`.
+    ///
+    /// The bounds start as `(min_num, max_num)` and narrow with every `TooHigh` or `TooLow` result.
+    pub fn bounds(&self) -> (u32, u32) {
+        (self.low, self.high)
+    }
 
-        let mut rng  = StdRng::from_seed(Default::default());
-        let default_game = Game::new(None, None, None, &mut rng);
-        assert_eq!(default_game.min_num(), Game::MIN_NUM);
-        assert_eq!(default_game.max_num(), Game::MAX_NUM);
-        assert_eq!(default_game.lives(), Game::LIVES);
+    /// Returns how many numbers are still possible given the current bounds.
+    ///
+    /// Excluded numbers inside the bounds are not counted.
+    pub fn possible_count(&self) -> u64 {
+        self.count_between(self.low, self.high)
     }
 
-    #[test]
-    fn test_play() {
-        let secret_number = 3;
-        let rng = StdRng::from_seed(Default::default());
-        let mut game = Game {
-            min_num: 1,
-            max_num: 10,
-            lives: 10,
-            rng,
-            secret_number,
-        };
+    /// Returns whether only one number is still possible, so a careful player already knows the
+    /// answer even if they have not guessed it yet.
+    ///
+    /// Exclusions count, so bounds holding one number that is in play and a few that are not
+    /// determine the answer too.
+    pub fn answer_is_determined(&self) -> bool {
+        self.possible_count() == 1
+    }
 
-        for _ in 0..8 {
-            assert_eq!(game.play(1), GuessResult::TooLow);
+    /// Returns how many numbers in `low..=high` are not excluded.
+    fn count_between(&self, low: u32, high: u32) -> u64 {
+        let excluded = self
+            .excluded
+            .iter()
+            .filter(|n| (low..=high).contains(n))
+            .count() as u64;
+        u64::from(high) - u64::from(low) + 1 - excluded
+    }
+
+    /// Describes the current bounds as a sentence suitable for screen readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let game = Game::new(Some(6), Some(12), Some(5), &mut rng);
+    /// assert_eq!(game.bounds_phrase(), "The number is between 6 and 12, 7 possibilities remaining.");
+    /// ```
+    pub fn bounds_phrase(&self) -> String {
+        if self.low == self.high {
+            format!("The number is {}, 1 possibility remaining.", self.low)
+        } else {
+            format!(
+                "The number is between {} and {}, {} possibilities remaining.",
+                self.low,
+                self.high,
+                self.possible_count()
+            )
         }
-        assert_eq!(game.play(20), GuessResult::TooHigh);
-        assert_eq!(game.play(3), GuessResult::Correct);
-        assert_eq!(game.play(1), GuessResult::TooLow);
     }
 
-    #[test]
-    fn test_compare() {
-        let comparisons = [
-            (5, 5, GuessResult::Correct),
-            (4, 5, GuessResult::TooLow),
-            (6, 5, GuessResult::TooHigh),
-        ];
-        for (guess, secret, result) in comparisons {
-            assert_eq!(compare(guess, secret), result);
+    /// Returns the guess a perfect player would make next: the middle of the current bounds.
+    pub fn suggest_guess(&self) -> u32 {
+        midpoint(self.low, self.high)
+    }
+
+    /// Returns the guess that leaves the fewest possible numbers in the worst case, or `None` once
+    /// the game is over or nothing is possible.
+    ///
+    /// Guessing the `k`th of `n` possible numbers leaves, at worst, the larger of the `k` below it
+    /// and the `n - k - 1` above it, so the lower median of the possible numbers is best. Without
+    /// exclusions that is the [`Game::suggest_guess`] midpoint; excluded numbers inside the bounds
+    /// can shift it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let game = Game::new(Some(1), Some(100), Some(7), &mut rng);
+    /// assert_eq!(game.minimax_guess(), Some(game.suggest_guess()));
+    /// let game = Game { excluded: vec![2, 3, 4], ..Game::new(Some(1), Some(10), Some(5), &mut rng) };
+    /// assert_eq!(game.minimax_guess(), Some(7));
+    /// ```
+    pub fn minimax_guess(&self) -> Option<u32> {
+        let possible = self.possible_count();
+        if self.is_over() || possible == 0 {
+            return None;
+        }
+
+        Some(self.lower_median(self.low, self.high))
+    }
+
+    /// Returns the lower median of the numbers in `low..=high` that are not excluded, of which
+    /// there must be at least one.
+    fn lower_median(&self, low: u32, high: u32) -> u32 {
+        let rank = (self.count_between(low, high) - 1) / 2 + 1;
+        let (mut start, mut end) = (low, high);
+        while start < end {
+            let mid = midpoint(start, end);
+            if self.count_between(low, mid) >= rank {
+                end = mid;
+            } else {
+                start = mid + 1;
+            }
+        }
+        start
+    }
+
+    /// Returns the chance that `guess` is the secret, given what the bounds already rule out.
+    ///
+    /// A guess inside the bounds has a `1 / possible_count` chance; one outside them has none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let game = Game::new(Some(1), Some(4), Some(5), &mut rng);
+    /// assert_eq!(game.correct_probability(2), 0.25);
+    /// assert_eq!(game.correct_probability(5), 0.0);
+    /// ```
+    pub fn correct_probability(&self, guess: u32) -> f64 {
+        if (self.low..=self.high).contains(&guess) && !self.excluded.contains(&guess) {
+            1.0 / self.possible_count() as f64
+        } else {
+            0.0
         }
     }
-}
\ No newline at end of file
+
+    /// Rates `guess` for a guidance overlay: how likely it is to win, how many numbers it should
+    /// rule out if it does not, and the two combined as the information it is expected to give.
+    ///
+    /// Every possible number is taken as equally likely. A guess outside the bounds or excluded
+    /// rates 0 on all three, and the middle of the bounds rates highest on the last two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let game = Game::new(Some(1), Some(5), Some(5), &mut rng);
+    /// let middle = game.guess_value(3);
+    /// assert_eq!(middle.chance_correct, 0.2);
+    /// assert_eq!(middle.expected_reduction, 3.0);
+    /// assert!(middle.information > game.guess_value(1).information);
+    /// ```
+    pub fn guess_value(&self, guess: u32) -> GuessValue {
+        let chance_correct = self.correct_probability(guess);
+        if chance_correct == 0.0 {
+            return GuessValue {
+                chance_correct,
+                expected_reduction: 0.0,
+                information: 0.0,
+            };
+        }
+
+        let possible = self.possible_count() as f64;
+        let below = if guess > self.low {
+            self.count_between(self.low, guess - 1) as f64
+        } else {
+            0.0
+        };
+        let above = possible - below - 1.0;
+        let expected_reduction = if possible > 1.0 {
+            possible - (below * below + above * above) / (possible - 1.0)
+        } else {
+            0.0
+        };
+        let information = [1.0, below, above]
+            .iter()
+            .filter(|&&count| count > 0.0)
+            .map(|&count| -(count / possible) * (count / possible).log2())
+            .sum();
+        GuessValue {
+            chance_correct,
+            expected_reduction,
+            information,
+        }
+    }
+
+    /// Returns the bits of information the suggested guess is expected to give, to show players
+    /// why the middle is the best guess.
+    ///
+    /// This is the `information` of [`Game::guess_value`] for [`Game::suggest_guess`]. Splitting
+    /// the bounds in half is worth 1 bit, and the middle's own chance of being the secret adds a
+    /// little more, which fades as the bounds grow. With one number left there is nothing to learn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let game = Game::new(Some(1), Some(1000), Some(10), &mut rng);
+    /// let bits = game.optimal_information_gain();
+    /// assert!(bits > 1.0 && bits < 1.02);
+    /// ```
+    pub fn optimal_information_gain(&self) -> f64 {
+        self.guess_value(self.suggest_guess()).information
+    }
+
+    /// Returns whether `guess` lies outside the current bounds, so it cannot be the secret and
+    /// would only waste a life.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 8,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// game.play(5);
+    /// assert!(game.is_wasteful(3));
+    /// assert!(!game.is_wasteful(7));
+    /// ```
+    pub fn is_wasteful(&self, guess: u32) -> bool {
+        !(self.low..=self.high).contains(&guess)
+    }
+
+    /// Counts the guesses that taught the player nothing because the number was already ruled out,
+    /// such as a repeat of an earlier guess, for coaching.
+    ///
+    /// Each guess is checked, as by [`Game::is_wasteful`], against the bounds the guess before it
+    /// left, with the first checked against the announced range. Bounds narrowed by hints between
+    /// guesses are not seen. With a `history_cap`, only the kept guesses count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 14,
+    ///     ..Game::new(Some(1), Some(20), Some(5), &mut rng)
+    /// };
+    /// game.play(10);
+    /// game.play(10);
+    /// assert_eq!(game.wasted_guesses(), 1);
+    /// ```
+    pub fn wasted_guesses(&self) -> u32 {
+        let mut bounds = self.announced_range();
+        let mut wasted = 0;
+        for record in &self.history {
+            if !(bounds.0..=bounds.1).contains(&record.guess) {
+                wasted += 1;
+            }
+            bounds = (record.low, record.high);
+        }
+        wasted
+    }
+
+    /// Returns how close the player's guesses came to the middle of the bounds on average, from
+    /// 1.0 for always guessing dead centre down to 0.0, or `None` before any guess.
+    ///
+    /// Each guess is scored against the bounds the guess before it left, with the first scored
+    /// against the announced range. Its distance from the nearest middle number (either one, when
+    /// the bounds hold an even count) is divided by the distance from there to the ends, and the
+    /// score is 1 minus that. A guess at either end or outside the bounds scores 0, while any guess
+    /// inside bounds of one or two numbers scores 1. Bounds narrowed by hints between guesses are
+    /// not seen. With a `history_cap`, only the kept guesses count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 5,
+    ///     ..Game::new(Some(1), Some(9), Some(5), &mut rng)
+    /// };
+    /// assert_eq!(game.bisection_accuracy(), None);
+    /// game.play(5);
+    /// assert_eq!(game.bisection_accuracy(), Some(1.0));
+    /// ```
+    pub fn bisection_accuracy(&self) -> Option<f64> {
+        if self.history.is_empty() {
+            return None;
+        }
+
+        let (mut low, mut high) = self.announced_range();
+        let mut total = 0.0;
+        for record in &self.history {
+            let guess = record.guess;
+            let lower_middle = midpoint(low, high);
+            let upper_middle = high - (high - low) / 2;
+            let reach = lower_middle - low;
+            total += if !(low..=high).contains(&guess) {
+                0.0
+            } else if reach == 0 {
+                1.0
+            } else {
+                let distance =
+                    lower_middle.saturating_sub(guess) + guess.saturating_sub(upper_middle);
+                1.0 - f64::from(distance) / f64::from(reach)
+            };
+            (low, high) = (record.low, record.high);
+        }
+        Some(total / self.history.len() as f64)
+    }
+
+    /// Returns the fraction of the announced range's slices that the player's guesses landed in,
+    /// from 0.0 before any guess to 1.0, as a measure of how spread out the guessing was.
+    ///
+    /// The range is cut into [`Game::EXPLORATION_BUCKETS`] slices as even as possible, or one per
+    /// number for smaller ranges. Guesses outside the range land in no slice. With a
+    /// `history_cap`, only the kept guesses count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 95,
+    ///     ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+    /// };
+    /// game.play(5);
+    /// game.play(50);
+    /// game.play(95);
+    /// assert_eq!(game.exploration_coverage(), 0.3);
+    /// ```
+    pub fn exploration_coverage(&self) -> f64 {
+        let (min_num, max_num) = self.announced_range();
+        let size = u64::from(max_num - min_num) + 1;
+        let buckets = size.min(Self::EXPLORATION_BUCKETS);
+        let mut touched: Vec<u64> = self
+            .history
+            .iter()
+            .filter(|record| (min_num..=max_num).contains(&record.guess))
+            .map(|record| u64::from(record.guess - min_num) * buckets / size)
+            .collect();
+        touched.sort_unstable();
+        touched.dedup();
+        touched.len() as f64 / buckets as f64
+    }
+
+    /// Returns whether the latest guess came strictly closer to the secret than every guess before
+    /// it, for a "getting warmer" nudge. The first guess has nothing to beat, so it is never the
+    /// closest yet. With a `history_cap`, only the kept guesses count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 30,
+    ///     ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+    /// };
+    /// game.play(50);
+    /// assert!(!game.was_closest_yet());
+    /// game.play(40);
+    /// assert!(game.was_closest_yet());
+    /// ```
+    pub fn was_closest_yet(&self) -> bool {
+        match self.history.split_last() {
+            Some((last, earlier)) if !earlier.is_empty() => {
+                earlier.iter().all(|record| last.distance < record.distance)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns how many more guesses than par the player took to win, or `None` unless the game
+    /// has been won.
+    ///
+    /// Par is the [`optimal_guesses`] a perfect player needs at most for the announced range, so a
+    /// win at or under par is 0 over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 4,
+    ///     ..Game::new(Some(1), Some(7), Some(10), &mut rng)
+    /// };
+    /// for guess in 1..=3 {
+    ///     game.play(guess);
+    /// }
+    /// assert_eq!(game.over_par(), None);
+    /// game.play(4);
+    /// assert_eq!(game.over_par(), Some(1));
+    /// ```
+    pub fn over_par(&self) -> Option<u32> {
+        if self.state() != GameState::Won {
+            return None;
+        }
+        let (low, high) = self.announced_range();
+        let par = optimal_guesses(self.count_between(low, high));
+        Some(self.attempts.saturating_sub(par))
+    }
+
+    /// Returns how dire things looked just before the winning guess, for picking highlights: the
+    /// chance, from 0.0 to 1.0, that a perfect player with the lives then left would have failed
+    /// to find the secret among the numbers then possible. Higher is a more dramatic win.
+    ///
+    /// Returns `None` unless the game is won, or if `history_cap` has dropped the winning guess.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 5,
+    ///     ..Game::new(Some(1), Some(10), Some(2), &mut rng)
+    /// };
+    /// assert_eq!(game.comeback_factor(), None);
+    /// game.play(5);
+    /// assert_eq!(game.comeback_factor(), Some(0.7));
+    /// ```
+    pub fn comeback_factor(&self) -> Option<f64> {
+        if self.state() != GameState::Won {
+            return None;
+        }
+        let winning = self
+            .history
+            .iter()
+            .position(|record| record.result == GuessResult::Correct)?;
+        let (low, high) = match winning.checked_sub(1) {
+            Some(before) => (self.history[before].low, self.history[before].high),
+            None => self.announced_range(),
+        };
+        let lives = self.history[winning].lives.min(64);
+        let covered = 2f64.powi(lives as i32) - 1.0;
+        let chance = (covered / self.count_between(low, high) as f64).min(1.0);
+        Some(1.0 - chance)
+    }
+
+    /// Predicts the bounds after guessing `guess`, without playing it.
+    ///
+    /// The secret is not consulted: the prediction assumes the worst case, where the secret lies
+    /// on the larger side of the guess (the upper side when both are the same size). A guess
+    /// outside the bounds leaves them unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let game = Game::new(Some(1), Some(100), Some(5), &mut rng);
+    /// assert_eq!(game.bounds_after(49), (50, 100));
+    /// assert_eq!(game.bounds_after(75), (1, 74));
+    /// ```
+    pub fn bounds_after(&self, guess: u32) -> (u32, u32) {
+        if self.low == self.high || !(self.low..=self.high).contains(&guess) {
+            return (self.low, self.high);
+        }
+
+        if self.high - guess >= guess - self.low {
+            (guess + 1, self.high)
+        } else {
+            (self.low, guess - 1)
+        }
+    }
+
+    /// Ends a game in progress as a loss by taking away all remaining lives.
+    ///
+    /// A game that is already over is left as it is.
+    pub fn give_up(&mut self) {
+        if self.state() == GameState::InProgress {
+            log_debug!("player gave up after {} attempts", self.attempts);
+            self.lives = 0;
+            self.lost_at.clear();
+        }
+    }
+
+    /// Gives up and returns how the game ended, for sessions cut short, such as by the input
+    /// closing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameOutcome, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 7,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// game.play(3);
+    /// assert_eq!(game.forfeit(), GameOutcome::Lost { attempts: 1, secret: 7 });
+    /// ```
+    pub fn forfeit(&mut self) -> GameOutcome {
+        self.give_up();
+        self.outcome().unwrap_or(GameOutcome::Lost {
+            attempts: self.attempts,
+            secret: self.secret_number,
+        })
+    }
+
+    /// Gives up when the player quits, such as with Ctrl-C, and returns the goodbye message to
+    /// show. The message names the secret when `reveal` is set.
+    ///
+    /// Front ends should then exit with [`QUIT_EXIT_CODE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 7,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// assert_eq!(game.quit(true), "Goodbye! The secret number was 7.");
+    /// assert!(game.is_over());
+    /// ```
+    pub fn quit(&mut self, reveal: bool) -> String {
+        self.give_up();
+        match self.reveal() {
+            Some(secret) if reveal => format!("Goodbye! The secret number was {secret}."),
+            _ => "Goodbye!".to_string(),
+        }
+    }
+
+    /// Wins the game at once by playing the secret number, for scripted tutorials and tests.
+    ///
+    /// Bonus mode is switched off first so the game really ends. A game that is already over is
+    /// left as it is.
+    #[cfg(feature = "testing")]
+    pub fn force_win(&mut self) {
+        if self.state() == GameState::InProgress {
+            self.bonus_mode = false;
+            self.play(self.secret_number);
+        }
+    }
+
+    /// Loses the game at once, as if the player gave up, for scripted tutorials and tests.
+    #[cfg(feature = "testing")]
+    pub fn force_lose(&mut self) {
+        self.give_up();
+    }
+
+    /// Returns the secret number whatever state the game is in, for scripted tutorials and tests.
+    #[cfg(feature = "testing")]
+    pub fn oracle_secret(&self) -> u32 {
+        self.secret_number
+    }
+
+    /// Returns whether the game has finished, either won or lost.
+    pub fn is_over(&self) -> bool {
+        self.state() != GameState::InProgress
+    }
+
+    /// Returns the secret number once the game is over, or `None` while it is still being played.
+    pub fn reveal(&self) -> Option<u32> {
+        if self.is_over() {
+            Some(self.secret_number)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the game is in progress, won or lost.
+    pub fn state(&self) -> GameState {
+        match self.outcome() {
+            None => GameState::InProgress,
+            Some(GameOutcome::Won { .. }) => GameState::Won,
+            Some(GameOutcome::Lost { .. }) => GameState::Lost,
+            Some(GameOutcome::Drawn { .. }) => GameState::Drawn,
+        }
+    }
+
+    /// Returns a snapshot of everything the player is allowed to see, leaving out the secret.
+    pub fn public_state(&self) -> PublicState {
+        let (min_num, max_num) = self.announced_range();
+        PublicState {
+            min_num,
+            max_num,
+            low: self.low,
+            high: self.high,
+            lives: self.lives(),
+            attempts: self.attempts,
+            state: self.state(),
+        }
+    }
+
+    /// Returns a [`Tracker`] holding the game's range and current bounds but not its secret, for
+    /// code that should reason about the search space without seeing the answer.
+    pub fn as_tracker(&self) -> Tracker {
+        let (min_num, max_num) = self.announced_range();
+        Tracker::new(min_num, max_num, self.low, self.high, &self.excluded)
+    }
+
+    /// Returns whether a perfect player could still be sure of winning with the lives left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// assert!(Game::new(Some(1), Some(100), Some(7), &mut rng).is_winnable());
+    /// assert!(!Game::new(Some(1), Some(100), Some(6), &mut rng).is_winnable());
+    /// ```
+    pub fn is_winnable(&self) -> bool {
+        optimal_guesses(self.possible_count()) <= self.lives()
+    }
+
+    /// Returns whether the game is beyond winnable and even a perfect player would find the secret
+    /// less often than [`Game::MERCY_CHANCE`].
+    ///
+    /// With `lives` guesses a perfect player covers at most `2^lives - 1` of the possible numbers.
+    fn is_hopeless(&self) -> bool {
+        let covered = 2f64.powi(self.lives().min(64) as i32) - 1.0;
+        !self.is_winnable() && covered / (self.possible_count() as f64) < Self::MERCY_CHANCE
+    }
+
+    /// Returns how many more guesses a perfect player would need, at worst, to find the secret
+    /// from here, counting down to 0 once it has been found.
+    ///
+    /// A single number left that has not been guessed yet still needs one guess.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 80,
+    ///     ..Game::new(Some(1), Some(100), Some(7), &mut rng)
+    /// };
+    /// assert_eq!(game.guesses_remaining_optimal(), 7);
+    /// game.play(50);
+    /// assert_eq!(game.guesses_remaining_optimal(), 6);
+    /// ```
+    pub fn guesses_remaining_optimal(&self) -> u32 {
+        if matches!(self.state(), GameState::Won | GameState::Drawn) {
+            0
+        } else {
+            optimal_guesses(self.possible_count())
+        }
+    }
+
+    /// Returns how many wrong guesses that teach nothing it would take to make the game unwinnable
+    /// for a perfect player, a tenser measure than the lives left.
+    ///
+    /// A perfect player still needs up to [`Game::guesses_remaining_optimal`] guesses, so the
+    /// game stays winnable while the lives cover them. With a single number left this equals the
+    /// lives; a game that is over or already unwinnable gives 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let game = Game::new(Some(1), Some(100), Some(10), &mut rng);
+    /// assert_eq!(game.lives_until_unwinnable(), 4);
+    /// ```
+    pub fn lives_until_unwinnable(&self) -> u32 {
+        if self.is_over() {
+            return 0;
+        }
+        (self.lives() + 1).saturating_sub(self.guesses_remaining_optimal())
+    }
+
+    /// Returns the lives left beyond the `ceil(log2(possible_count))` guesses it takes to halve the
+    /// remaining numbers down to one, for a stress meter: positive when the player has slack and
+    /// negative when they are behind the pace of a perfect player.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// assert_eq!(Game::new(Some(1), Some(100), Some(10), &mut rng).lives_buffer(), 3);
+    /// assert_eq!(Game::new(Some(1), Some(100), Some(5), &mut rng).lives_buffer(), -2);
+    /// ```
+    pub fn lives_buffer(&self) -> i64 {
+        let halvings = u64::BITS - self.possible_count().saturating_sub(1).leading_zeros();
+        i64::from(self.lives()) - i64::from(halvings)
+    }
+
+    /// Counts the distinct ways the game could still end: a win on each guess from here that a
+    /// win is still possible on, plus a loss if the lives can run out first.
+    ///
+    /// Only guesses inside the bounds are considered, each of which rules out at least one
+    /// number, so with `n` numbers possible and `l` wrong guesses to spare a win can come on any
+    /// of the next `min(n, l)` guesses and a loss is reachable when `n > l`. A pending free first
+    /// guess counts as one more to spare. A game that is over has only its own end state left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// // Wins on guesses 1, 2 or 3, or a loss.
+    /// assert_eq!(Game::new(Some(1), Some(10), Some(3), &mut rng).reachable_states(), 4);
+    /// // Lives to spare, so only wins on guesses 1 to 3.
+    /// assert_eq!(Game::new(Some(1), Some(3), Some(5), &mut rng).reachable_states(), 3);
+    /// ```
+    pub fn reachable_states(&self) -> usize {
+        if self.is_over() {
+            return 1;
+        }
+
+        let possible = self.possible_count();
+        let free = u64::from(self.free_first_guess && self.attempts == 0);
+        let spare = u64::from(self.lives()) + free;
+        let wins = possible.min(spare);
+        let loss = u64::from(possible > spare);
+        usize::try_from(wins + loss).unwrap_or(usize::MAX)
+    }
+
+    /// Spends a life to learn which half of the current bounds holds the secret.
+    ///
+    /// The bounds are split at their midpoint, with the lower half taking the midpoint itself.
+    /// Returns `Some(true)` when the secret is in the lower half and `Some(false)` when it is in
+    /// the upper half, narrowing the bounds to that half. Returns `None` without doing anything
+    /// once the game is over. With `free_hints` set, no life is spent, and with a
+    /// `hint_budget` the hint comes out of the budget instead, returning `None` once it is spent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 3,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// assert_eq!(game.hint_half(), Some(true));
+    /// assert_eq!(game.bounds(), (1, 5));
+    /// assert_eq!(game.lives(), 4);
+    /// ```
+    pub fn hint_half(&mut self) -> Option<bool> {
+        if !self.take_hint(Instant::now()) {
+            return None;
+        }
+
+        let mid = midpoint(self.low, self.high);
+        let lower = self.secret_number <= mid;
+        if lower {
+            self.high = mid;
+        } else {
+            self.low = mid + 1;
+        }
+        log_debug!("half hint used, {} lives left", self.lives);
+        Some(lower)
+    }
+
+    /// Spends a life to learn bit `index` of the secret, counting from the least significant bit
+    /// at 0, for players who can deduce from binary.
+    ///
+    /// The bounds are left as they are, since a bit rarely rules out one unbroken run of numbers.
+    /// Returns `None` without doing anything once the game is over or when `index` is not below
+    /// the bit width of the announced maximum, so a looser announced range does not give away the
+    /// real one. Hints are paid for as with [`Game::hint_half`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 6,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// assert_eq!(game.hint_bit(0), Some(false));
+    /// assert_eq!(game.hint_bit(2), Some(true));
+    /// assert_eq!(game.hint_bit(4), None);
+    /// assert_eq!(game.lives(), 3);
+    /// ```
+    pub fn hint_bit(&mut self, index: u32) -> Option<bool> {
+        let width = u32::BITS - self.announced_range().1.leading_zeros();
+        if index >= width || !self.take_hint(Instant::now()) {
+            return None;
+        }
+
+        log_debug!("bit {} hint used, {} lives left", index, self.lives);
+        Some(self.secret_number >> index & 1 == 1)
+    }
+
+    /// Returns how many hints can still be taken: what is left of the `hint_budget`, or without
+    /// one the lives left, which hints cost. Free hints without a budget are only limited by the
+    /// game being over, shown as `u32::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameConfig, GameTrait};
+    ///
+    /// let config = GameConfig { lives: 5, hint_budget: Some(2), ..GameConfig::default() };
+    /// let mut game = Game::from_seed(1, config);
+    /// game.hint_half();
+    /// assert_eq!(game.hints_remaining(), 1);
+    /// assert_eq!(game.lives(), 5);
+    /// ```
+    pub fn hints_remaining(&self) -> u32 {
+        if self.is_over() {
+            return 0;
+        }
+        match self.hint_budget {
+            Some(budget) => budget,
+            None if self.free_hints => u32::MAX,
+            None => self.lives(),
+        }
+    }
+
+    /// Pays for a hint, from the `hint_budget` if there is one and otherwise with a life unless
+    /// hints are free. Returns `false` without paying when the game is over or the budget is
+    /// spent.
+    fn take_hint(&mut self, now: Instant) -> bool {
+        self.settle_regen(now);
+        if self.is_over() || self.hint_budget == Some(0) {
+            return false;
+        }
+
+        match &mut self.hint_budget {
+            Some(budget) => *budget -= 1,
+            None if !self.free_hints => self.lose_life(now),
+            None => {}
+        }
+        true
+    }
+
+    /// Returns the lives available at `now`, including any that have regenerated by then.
+    ///
+    /// When `regen_cooldown` is set, every lost life comes back once the cooldown has passed since
+    /// it was lost, up to `initial_lives`, unless the game was lost first. Without a cooldown this
+    /// is just the current lives.
+    pub fn lives_at(&self, now: Instant) -> u32 {
+        match self.regen_cooldown {
+            Some(cooldown) => {
+                let regenerated = self
+                    .lost_at
+                    .iter()
+                    .filter(|&&lost| now.saturating_duration_since(lost) >= cooldown)
+                    .count() as u32;
+                (self.lives + regenerated).min(self.initial_lives.max(self.lives))
+            }
+            None => self.lives,
+        }
+    }
+
+    /// Moves any lives that have regenerated by `now` back into `lives`.
+    fn settle_regen(&mut self, now: Instant) {
+        if let Some(cooldown) = self.regen_cooldown {
+            self.lives = self.lives_at(now);
+            self.lost_at
+                .retain(|&lost| now.saturating_duration_since(lost) < cooldown);
+        }
+    }
+
+    /// Takes away a life, remembering when it was lost if lives regenerate.
+    ///
+    /// Losing the last life ends the game for good, so nothing regenerates after it.
+    fn lose_life(&mut self, now: Instant) {
+        self.lives -= 1;
+        if self.lives == 0 {
+            self.lost_at.clear();
+        } else if self.regen_cooldown.is_some() {
+            self.lost_at.push(now);
+        }
+    }
+
+    /// Plays a guess like [`GameTrait::play`], recording `now` as the time it was made.
+    ///
+    /// `now` is also used for life regeneration, so a simulated clock can be passed in.
+    pub fn play_at(&mut self, guess: u32, now: Instant) -> GuessResult {
+        let attempts = self.attempts;
+        let result = self.judge(guess, now);
+        if self.attempts > attempts {
+            self.guess_times.push(now);
+            if let Some(cap) = self.history_cap {
+                let excess = self.guess_times.len().saturating_sub(cap);
+                self.guess_times.drain(..excess);
+            }
+        }
+        result
+    }
+
+    /// Explains what the last guess taught the player, in tutorial mode.
+    ///
+    /// Returns `None` outside tutorial mode or before the first guess.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 7,
+    ///     tutorial: true,
+    ///     ..Game::new(Some(1), Some(20), Some(5), &mut rng)
+    /// };
+    /// game.play(15);
+    /// assert_eq!(
+    ///     game.last_explanation().as_deref(),
+    ///     Some("Since 15 was too high, the number is between 1 and 14.")
+    /// );
+    /// ```
+    pub fn last_explanation(&self) -> Option<String> {
+        if !self.tutorial {
+            return None;
+        }
+
+        let record = self.history.last()?;
+        let bounds = if record.low == record.high {
+            format!("the number must be {}", record.low)
+        } else {
+            format!("the number is between {} and {}", record.low, record.high)
+        };
+        let explanation = match record.result {
+            GuessResult::Correct => format!("{} was the number. Well done!", record.guess),
+            GuessResult::Draw => format!(
+                "{} was the number, but it took your last life, so the game is a draw.",
+                record.guess
+            ),
+            GuessResult::TooHigh => format!("Since {} was too high, {bounds}.", record.guess),
+            GuessResult::TooLow => format!("Since {} was too low, {bounds}.", record.guess),
+            GuessResult::Quartile(quarter) => format!(
+                "{} was wrong, but the number is in the {} quarter, so {bounds}.",
+                record.guess,
+                quarter_name(quarter)
+            ),
+            GuessResult::MercyLoss => format!(
+                "{} was wrong, and with so few lives left the number was out of reach, so the \
+                 game was ended early.",
+                record.guess
+            ),
+            GuessResult::NoMoreLives | GuessResult::Excluded => return None,
+        };
+        Some(explanation)
+    }
+
+    /// Parses typed input with [`parse_guess`], checking it against the range the player was
+    /// given, and plays it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GuessInputError`] without playing anything if the input is empty, not a number
+    /// or out of range. Input out of range still costs `out_of_range_penalty` lives while the game
+    /// is in progress, though it is not counted as an attempt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait, GuessInputError, GuessResult};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 7,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// assert_eq!(game.apply_guess_str(" 7\n"), Ok(GuessResult::Correct));
+    /// assert_eq!(game.apply_guess_str("seven"), Err(GuessInputError::NotANumber));
+    /// ```
+    pub fn apply_guess_str(&mut self, input: &str) -> Result<GuessResult, GuessInputError> {
+        let (min, max) = self.announced_range();
+        let guess = match parse_guess(input, min, max) {
+            Err(err @ GuessInputError::OutOfRange { .. }) => {
+                if !self.is_over() {
+                    let now = Instant::now();
+                    self.settle_regen(now);
+                    for _ in 0..self.out_of_range_penalty.min(self.lives) {
+                        self.lose_life(now);
+                    }
+                    log_debug!("out-of-range input, {} lives left", self.lives);
+                }
+                return Err(err);
+            }
+            parsed => parsed?,
+        };
+        Ok(self.play(guess))
+    }
+
+    /// Explains a result the game just gave for `guess`: what it means for the bounds and the best
+    /// guess to try next.
+    ///
+    /// Call this right after playing the guess, as it reads the bounds as they are now.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 80,
+    ///     ..Game::new(Some(1), Some(100), Some(7), &mut rng)
+    /// };
+    /// let result = game.play(42);
+    /// assert_eq!(
+    ///     game.explain(42, &result),
+    ///     "42 was too low, so the answer is between 43 and 100 — try the middle, around 71."
+    /// );
+    /// ```
+    pub fn explain(&self, guess: u32, result: &GuessResult) -> String {
+        let direction = match result {
+            GuessResult::Correct => return format!("{guess} was right — the answer is {guess}."),
+            GuessResult::Draw => {
+                return format!("{guess} was right, but on your last life — it's a draw.")
+            }
+            GuessResult::NoMoreLives => {
+                return "The game is over, so there is nothing left to guess.".to_string()
+            }
+            GuessResult::MercyLoss => {
+                return format!(
+                    "{guess} was wrong, and the number is now out of reach — the game is over."
+                )
+            }
+            GuessResult::Excluded => {
+                return format!("{guess} is not in play, so it tells you nothing — try again.")
+            }
+            GuessResult::Quartile(quarter) => {
+                return format!(
+                    "{guess} was wrong, but the answer is in the {} quarter, between {} and {}.",
+                    quarter_name(*quarter),
+                    self.low,
+                    self.high
+                )
+            }
+            GuessResult::TooHigh => "high",
+            GuessResult::TooLow => "low",
+        };
+        if self.low == self.high {
+            format!(
+                "{guess} was too {direction}, so the answer must be {} — guess it!",
+                self.low
+            )
+        } else {
+            format!(
+                "{guess} was too {direction}, so the answer is between {} and {} — try the \
+                 middle, around {}.",
+                self.low,
+                self.high,
+                self.suggest_guess()
+            )
+        }
+    }
+
+    /// Returns when each guess made through [`Game::play_at`] was made, in order.
+    ///
+    /// Guesses made with plain [`GameTrait::play`] have no clock, so they are left out. With a
+    /// `history_cap`, only the most recent timestamps are kept.
+    pub fn guess_timestamps(&self) -> &[Instant] {
+        &self.guess_times
+    }
+
+    /// Returns the average time between consecutive timestamped guesses, or `None` with fewer
+    /// than two of them. With a `history_cap`, only the kept timestamps are averaged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game::new(Some(1), Some(100), Some(10), &mut rng);
+    /// let start = Instant::now();
+    /// game.play_at(101, start);
+    /// assert_eq!(game.average_think_time(), None);
+    /// game.play_at(102, start + Duration::from_secs(4));
+    /// assert_eq!(game.average_think_time(), Some(Duration::from_secs(4)));
+    /// ```
+    pub fn average_think_time(&self) -> Option<Duration> {
+        match self.guess_times.as_slice() {
+            [first, .., last] => Some((*last - *first) / (self.guess_times.len() as u32 - 1)),
+            _ => None,
+        }
+    }
+
+    /// Narrows the bounds as if `guess` were `direction` of the secret, which is the true order
+    /// unless a lie was told, when it is the opposite.
+    ///
+    /// A direction that contradicts the earlier ones starts the bounds again from the announced
+    /// range on its side of the guess, and one that would leave nothing in play is ignored.
+    fn narrow_reported(&mut self, guess: u32, direction: GuessResult) {
+        let (min_num, max_num) = self.announced_range();
+        let side = match direction {
+            GuessResult::TooHigh if guess > min_num => (min_num, guess - 1),
+            GuessResult::TooLow if guess < max_num => (guess + 1, max_num),
+            _ => return,
+        };
+        let narrowed = (self.low.max(side.0), self.high.min(side.1));
+        for (low, high) in [narrowed, side] {
+            if low <= high && self.count_between(low, high) > 0 {
+                self.low = low;
+                self.high = high;
+                return;
+            }
+        }
+    }
+
+    /// Plays `guess` as of `now`, which is when any life it costs is counted as lost.
+    fn judge(&mut self, guess: u32, now: Instant) -> GuessResult {
+        self.settle_regen(now);
+        if self.is_over() {
+            log_debug!("guess {} rejected: the game is over", guess);
+            return GuessResult::NoMoreLives;
+        }
+        if self.excluded.contains(&guess) {
+            log_debug!("guess {} rejected: excluded", guess);
+            return GuessResult::Excluded;
+        }
+
+        let mut result = self.strategy.compare(guess, self.secret_number);
+        let lied = matches!(result, GuessResult::TooHigh | GuessResult::TooLow)
+            && self.lie_chance > 0.0
+            && self.rng.gen_bool(self.lie_chance.min(1.0));
+        if lied {
+            log_trace!("lying about guess {}", guess);
+            result = result.inverted();
+        }
+        let (truth, distance) = compare_verbose(guess, self.secret_number);
+        let quarter = (self.quartile_hints && truth != GuessResult::Correct)
+            .then(|| quartile_of(self.low, self.high, self.secret_number));
+        if let Some(quarter) = quarter {
+            result = GuessResult::Quartile(quarter);
+        }
+        let (low_before, high_before) = (self.low, self.high);
+        let free = self.free_first_guess && self.attempts == 0;
+        self.attempts += 1;
+        if truth == GuessResult::Correct {
+            self.find_streak += 1;
+        } else {
+            self.find_streak = 0;
+        }
+        match truth {
+            GuessResult::Correct if self.bonus_mode => {
+                if self.won {
+                    self.bonus_count += 1;
+                }
+                self.won = true;
+                self.start_bonus_round();
+            }
+            GuessResult::Correct if self.require_spare_life && self.lives == 1 => {
+                self.drawn = true;
+                self.low = guess;
+                self.high = guess;
+                result = GuessResult::Draw;
+            }
+            GuessResult::Correct => {
+                self.won = true;
+                self.low = guess;
+                self.high = guess;
+            }
+            GuessResult::TooHigh | GuessResult::TooLow => {
+                if self.lie_chance > 0.0 {
+                    let direction = if lied { truth.inverted() } else { truth };
+                    self.narrow_reported(guess, direction);
+                } else if truth == GuessResult::TooHigh {
+                    self.high = self.high.min(guess.saturating_sub(1));
+                } else {
+                    self.low = self.low.max(guess.saturating_add(1));
+                }
+                if !free {
+                    self.lose_life(now);
+                }
+            }
+            GuessResult::NoMoreLives
+            | GuessResult::Excluded
+            | GuessResult::Draw
+            | GuessResult::MercyLoss
+            | GuessResult::Quartile(_) => {}
+        }
+        if let Some(quarter) = quarter {
+            (self.low, self.high) = quartile_bounds(low_before, high_before, quarter, guess);
+        }
+        if self.mercy_rule
+            && matches!(truth, GuessResult::TooHigh | GuessResult::TooLow)
+            && self.lives > 0
+            && self.is_hopeless()
+        {
+            log_debug!("mercy rule ends the game after guess {}", guess);
+            self.give_up();
+            result = GuessResult::MercyLoss;
+        }
+        self.history.push(GuessRecord {
+            guess,
+            result,
+            distance,
+            low: self.low,
+            high: self.high,
+            lives: self.lives,
+        });
+        if let Some(cap) = self.history_cap {
+            let excess = self.history.len().saturating_sub(cap);
+            self.history.drain(..excess);
+        }
+        log_debug!(
+            "guess {} was {:?}, {} lives left",
+            guess,
+            result,
+            self.lives
+        );
+        match self.outcome() {
+            Some(GameOutcome::Won { attempts, score }) => {
+                log_debug!("game won after {} attempts, scoring {}", attempts, score);
+            }
+            Some(GameOutcome::Lost { attempts, .. }) => {
+                log_debug!("game lost after {} attempts", attempts);
+            }
+            Some(GameOutcome::Drawn { attempts }) => {
+                log_debug!("game drawn after {} attempts", attempts);
+            }
+            None => {}
+        }
+        result
+    }
+
+    /// Returns the game's history as frames to step through in a replay: the starting state,
+    /// then the state after each guess.
+    ///
+    /// With a `history_cap`, guesses that were dropped are missing, so the replay jumps from the
+    /// starting state to the oldest guess kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 7,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// game.play(5);
+    /// let frames = game.replay_frames();
+    /// assert_eq!(frames.len(), 2);
+    /// assert_eq!((frames[0].low, frames[0].high), (1, 10));
+    /// assert_eq!((frames[1].low, frames[1].high), (6, 10));
+    /// ```
+    pub fn replay_frames(&self) -> Vec<ReplayFrame> {
+        let (low, high) = self.announced_range();
+        let start = ReplayFrame {
+            guess: None,
+            result: None,
+            low,
+            high,
+            lives: self.initial_lives,
+        };
+        std::iter::once(start)
+            .chain(self.history.iter().map(|record| ReplayFrame {
+                guess: Some(record.guess),
+                result: Some(record.result),
+                low: record.low,
+                high: record.high,
+                lives: record.lives,
+            }))
+            .collect()
+    }
+
+    /// Returns how many wrong guesses were just one away from the secret, among those kept in the
+    /// history.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 7,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// game.play(6);
+    /// assert_eq!(game.missed_wins(), 1);
+    /// ```
+    pub fn missed_wins(&self) -> usize {
+        self.history
+            .iter()
+            .filter(|record| record.distance == 1)
+            .count()
+    }
+
+    /// Returns whether the player's guesses lean towards the low or high end of the full range.
+    ///
+    /// The average guess is measured against the middle of the range. It counts as a bias once it
+    /// is more than `BIAS_THRESHOLD` of half the range away; a game with no guesses is `Balanced`.
+    /// With a `history_cap`, only the kept guesses count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Bias, Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 90,
+    ///     ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+    /// };
+    /// game.play(20);
+    /// game.play(30);
+    /// assert_eq!(game.bias_toward(), Bias::Low);
+    /// ```
+    pub fn bias_toward(&self) -> Bias {
+        if self.history.is_empty() || self.min_num == self.max_num {
+            return Bias::Balanced;
+        }
+
+        let middle = (f64::from(self.min_num) + f64::from(self.max_num)) / 2.0;
+        let half_range = (f64::from(self.max_num) - f64::from(self.min_num)) / 2.0;
+        let offset = self
+            .history
+            .iter()
+            .map(|record| f64::from(record.guess) - middle)
+            .sum::<f64>()
+            / self.history.len() as f64;
+        if offset < -Self::BIAS_THRESHOLD * half_range {
+            Bias::Low
+        } else if offset > Self::BIAS_THRESHOLD * half_range {
+            Bias::High
+        } else {
+            Bias::Balanced
+        }
+    }
+
+    /// Returns whether the player is scanning through the numbers one step at a time instead of
+    /// halving the range, so a coach can suggest guessing the middle.
+    ///
+    /// This needs at least four guesses, each higher than the last or each lower, where the
+    /// largest step is no more than twice the smallest. Halving makes every step about half the
+    /// one before, so it never fits. With a `history_cap`, only the kept guesses count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 90,
+    ///     ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+    /// };
+    /// for guess in [10, 20, 30, 40] {
+    ///     game.play(guess);
+    /// }
+    /// assert!(game.is_linear_scanning());
+    /// ```
+    pub fn is_linear_scanning(&self) -> bool {
+        if self.history.len() < 4 {
+            return false;
+        }
+
+        let steps: Vec<i64> = self
+            .history
+            .windows(2)
+            .map(|pair| i64::from(pair[1].guess) - i64::from(pair[0].guess))
+            .collect();
+        let same_direction =
+            steps.iter().all(|&step| step > 0) || steps.iter().all(|&step| step < 0);
+        let smallest = steps.iter().map(|step| step.abs()).min().unwrap_or(0);
+        let largest = steps.iter().map(|step| step.abs()).max().unwrap_or(0);
+        same_direction && largest <= 2 * smallest
+    }
+
+    /// Draws how many numbers were still possible at the start and after each guess as a Unicode
+    /// sparkline, scaled so the tallest bar is the largest count. With a `history_cap`, dropped
+    /// guesses have no bar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 3,
+    ///     ..Game::new(Some(1), Some(8), Some(5), &mut rng)
+    /// };
+    /// game.play(4);
+    /// game.play(2);
+    /// assert_eq!(game.narrowing_sparkline(), "█▃▁");
+    /// ```
+    pub fn narrowing_sparkline(&self) -> String {
+        const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+        let (low, high) = self.announced_range();
+        let counts: Vec<u64> = std::iter::once(self.count_between(low, high))
+            .chain(
+                self.history
+                    .iter()
+                    .map(|record| self.count_between(record.low, record.high)),
+            )
+            .collect();
+        let max = counts.iter().copied().max().unwrap_or(1).max(2);
+        let top = BARS.len() as u64 - 1;
+        counts
+            .iter()
+            .map(|&count| {
+                let level = (count.saturating_sub(1) * top + (max - 1) / 2) / (max - 1);
+                BARS[level as usize]
+            })
+            .collect()
+    }
+
+    /// Returns the guesses a perfect player would have made to find the secret, by always guessing
+    /// the middle of the full range and narrowing from there.
+    ///
+    /// Excluded numbers are never guessed: each guess is the lower median of the numbers still in
+    /// play, as with [`Game::minimax_guess`], which is the midpoint when nothing is excluded.
+    ///
+    /// The solution gives the secret away, so it is only available once the game is over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 3,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// assert_eq!(game.optimal_solution(), None);
+    /// game.play(3);
+    /// assert_eq!(game.optimal_solution(), Some(vec![5, 2, 3]));
+    /// ```
+    pub fn optimal_solution(&self) -> Option<Vec<u32>> {
+        if self.state() == GameState::InProgress {
+            return None;
+        }
+
+        let (mut low, mut high) = (self.min_num, self.max_num);
+        let mut guesses = Vec::new();
+        loop {
+            let guess = self.lower_median(low, high);
+            guesses.push(guess);
+            match compare(guess, self.secret_number) {
+                GuessResult::TooLow => low = guess + 1,
+                GuessResult::TooHigh => high = guess - 1,
+                _ => return Some(guesses),
+            }
+        }
+    }
+
+    /// Returns how many secrets in a row the player has found without a wrong guess in between,
+    /// which in bonus mode rewards finding each new secret at the first try.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     bonus_mode: true,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// game.play(game.secret_number);
+    /// game.play(game.secret_number);
+    /// assert_eq!(game.current_find_streak(), 2);
+    /// ```
+    pub fn current_find_streak(&self) -> u32 {
+        self.find_streak
+    }
+
+    /// Returns where the secret sorts among the player's guesses once the game is over: the number
+    /// of guesses that were below it. With a `history_cap`, only the kept guesses count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 6,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// game.play(3);
+    /// game.play(8);
+    /// assert_eq!(game.secret_rank_among_guesses(), None);
+    /// game.play(6);
+    /// assert_eq!(game.secret_rank_among_guesses(), Some(1));
+    /// ```
+    pub fn secret_rank_among_guesses(&self) -> Option<usize> {
+        if self.state() == GameState::InProgress {
+            return None;
+        }
+
+        Some(
+            self.history
+                .iter()
+                .filter(|record| record.guess < self.secret_number)
+                .count(),
+        )
+    }
+
+    /// Returns how surprising the secret was, in bits, once the game is over.
+    ///
+    /// This is `-log2(p)`, where `p` is the chance the secret had of being drawn. For a uniform
+    /// draw it is `log2` of the number of possible secrets; under a weighted distribution, rarer
+    /// secrets score higher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game::new(Some(1), Some(64), Some(6), &mut rng);
+    /// assert_eq!(game.secret_surprisal(), None);
+    /// game.give_up();
+    /// assert_eq!(game.secret_surprisal(), Some(6.0));
+    /// ```
+    pub fn secret_surprisal(&self) -> Option<f64> {
+        if self.state() == GameState::InProgress {
+            return None;
+        }
+
+        let probability = match self.distribution {
+            SecretDistribution::Uniform => {
+                let possible = u64::from(self.max_num - self.min_num) + 1
+                    - self.secret_exclusions().len() as u64;
+                1.0 / possible as f64
+            }
+            distribution => {
+                distribution.probability(self.secret_number, self.min_num, self.max_num)
+            }
+        };
+        Some(-probability.log2())
+    }
+
+    /// Returns the score for the game.
+    ///
+    /// A won game scores `100 * (lives + 1) / attempts`, so winning quickly and with lives to spare
+    /// scores highest, plus `BONUS_POINTS` for every bonus number found. A game that has not been
+    /// won scores 0. The total is capped at `max_score` when one is set.
+    pub fn score(&self) -> u32 {
+        if !self.won {
+            return 0;
+        }
+        let score =
+            100 * (self.lives + 1) / self.attempts.max(1) + self.bonus_count * Self::BONUS_POINTS;
+        self.max_score.map_or(score, |cap| score.min(cap))
+    }
+
+    /// Returns the score weighted by how quickly the game was won, or `None` unless it was won
+    /// with timestamped guesses from [`Game::play_at`].
+    ///
+    /// The time taken runs from the first timestamped guess to the last, and the score halves for
+    /// every [`Game::TIMED_SCORE_HALF_LIFE`] of it: `score * 0.5^(elapsed / half_life)`, rounded.
+    /// A game won in one guess keeps its full score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 8,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// let start = Instant::now();
+    /// game.play_at(5, start);
+    /// game.play_at(8, start + Game::TIMED_SCORE_HALF_LIFE);
+    /// assert_eq!(game.score(), 250);
+    /// assert_eq!(game.timed_score(), Some(125));
+    /// ```
+    pub fn timed_score(&self) -> Option<u32> {
+        let (first, last) = match self.guess_times.as_slice() {
+            [first, .., last] => (*first, *last),
+            [only] => (*only, *only),
+            [] => return None,
+        };
+        if !self.won {
+            return None;
+        }
+        let half_lives = (last - first).as_secs_f64() / Self::TIMED_SCORE_HALF_LIFE.as_secs_f64();
+        Some((f64::from(self.score()) * 0.5f64.powf(half_lives)).round() as u32)
+    }
+
+    /// Returns the score a perfect player is sure to reach with the game's range and lives, used to
+    /// put games of different difficulties on the same footing.
+    ///
+    /// A perfect player needs at most `optimal_guesses` for the starting range, losing a life on
+    /// each but the last, so harder games have lower scores here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// assert_eq!(Game::new(Some(1), Some(20), Some(10), &mut rng).difficulty_score(), 140.0);
+    /// assert_eq!(Game::new(Some(1), Some(1000), Some(12), &mut rng).difficulty_score(), 40.0);
+    /// ```
+    pub fn difficulty_score(&self) -> f64 {
+        let (low, high) = self.announced_range();
+        let guesses = optimal_guesses(self.count_between(low, high));
+        let lives = self.initial_lives.saturating_sub(guesses - 1);
+        100.0 * f64::from(lives + 1) / f64::from(guesses)
+    }
+
+    /// Returns the score a player can expect by guessing at random from the start, for setting
+    /// score thresholds that mean something.
+    ///
+    /// The random player picks uniformly among the numbers in the range they have not tried yet
+    /// and ignores the feedback, so with `n` numbers each guess `k` up to `n` is equally likely
+    /// to be the winning one. A win only scores if it comes before the lives run out. Bonus
+    /// rounds are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::Game;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// // Half the time the first guess wins with 2 lives (300), otherwise the second with 1 (100).
+    /// let game = Game::new(Some(1), Some(2), Some(2), &mut rng);
+    /// assert_eq!(game.expected_random_score(), 200.0);
+    /// ```
+    pub fn expected_random_score(&self) -> f64 {
+        let candidates = self.count_between(self.min_num, self.max_num);
+        let free = u32::from(self.free_first_guess);
+        let mut expected = 0.0;
+        let mut attempts: u32 = 1;
+        while u64::from(attempts) <= candidates {
+            let lost = (attempts - 1).saturating_sub(free);
+            if lost >= self.initial_lives {
+                break;
+            }
+            let score = 100 * (self.initial_lives - lost + 1) / attempts;
+            expected += f64::from(score);
+            attempts += 1;
+        }
+        expected / candidates as f64
+    }
+
+    /// Returns the score divided by [`Game::difficulty_score`], so wins from easy and hard games
+    /// can share one leaderboard. Playing perfectly scores about 1.0 whatever the difficulty.
+    pub fn normalized_score(&self) -> f64 {
+        f64::from(self.score()) / self.difficulty_score()
+    }
+
+    /// Draws a secret for a new round from the game's distribution, using `secret_rng` if it is set
+    /// and `rng` otherwise.
+    ///
+    /// The secret is never an excluded number, nor an obvious one with `avoid_obvious` set. A
+    /// weighted distribution draws again until it misses them.
+    fn draw_round_secret(&mut self) -> u32 {
+        let avoided = self.secret_exclusions();
+        let rng: &mut dyn RngCore = match &mut self.secret_rng {
+            Some(rng) => rng,
+            None => &mut self.rng,
+        };
+        match self.distribution {
+            SecretDistribution::Uniform => draw_secret(rng, self.min_num, self.max_num, &avoided),
+            distribution => loop {
+                let secret = distribution.sample(rng, self.min_num, self.max_num);
+                if !avoided.contains(&secret) {
+                    break secret;
+                }
+            },
+        }
+    }
+
+    /// Returns the values a secret is never drawn from, sorted and without duplicates: the
+    /// excluded numbers, plus the obvious ones when `avoid_obvious` is set.
+    fn secret_exclusions(&self) -> Vec<u32> {
+        let mut avoided = self.excluded.clone();
+        if self.avoid_obvious {
+            avoided.extend([
+                self.min_num,
+                midpoint(self.min_num, self.max_num),
+                self.max_num,
+            ]);
+            avoided.sort_unstable();
+            avoided.dedup();
+        }
+        avoided
+    }
+
+    /// Draws a fresh secret from the full range and resets the bounds, keeping lives and attempts.
+    ///
+    /// Used by bonus mode to chain into the next number after a correct guess.
+    fn start_bonus_round(&mut self) {
+        self.secret_number = self.draw_round_secret();
+        log_debug!(
+            "bonus round: secret drawn from {}..={}",
+            self.min_num,
+            self.max_num
+        );
+        log_trace!("secret number is {}", self.secret_number);
+        (self.low, self.high) = self.announced_range();
+    }
+
+    /// Returns the outcome of the game, or `None` while it is still in progress.
+    ///
+    /// In bonus mode the game carries on after a correct guess, so it only finishes once the
+    /// lives run out.
+    pub fn outcome(&self) -> Option<GameOutcome> {
+        if self.bonus_mode && self.lives > 0 {
+            None
+        } else if self.drawn {
+            Some(GameOutcome::Drawn {
+                attempts: self.attempts,
+            })
+        } else if self.won {
+            Some(GameOutcome::Won {
+                attempts: self.attempts,
+                score: self.score(),
+            })
+        } else if self.lives == 0 {
+            Some(GameOutcome::Lost {
+                attempts: self.attempts,
+                secret: self.secret_number,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Compares this finished game with a recorded result for the same puzzle, such as a friend's,
+    /// or returns `None` while the game is still being played.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameOutcome, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 7,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// let friend = GameOutcome::Won { attempts: 3, score: 100 };
+    /// assert_eq!(game.compare_to(&friend), None);
+    /// game.play(7);
+    /// assert!(game.compare_to(&friend).unwrap().beat());
+    /// ```
+    pub fn compare_to(&self, other: &GameOutcome) -> Option<Comparison> {
+        let outcome = self.outcome()?;
+        Some(Comparison {
+            attempts: outcome.attempts().cmp(&other.attempts()),
+            score: outcome.score().cmp(&other.score()),
+        })
+    }
+}
+
+/// The settings a game is created with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameConfig {
+    pub min_num: u32,
+    pub max_num: u32,
+    pub lives: u32,
+    /// Plays the suggested first guess automatically when the game starts, so beginners begin
+    /// from a narrowed range.
+    pub cold_start: bool,
+    /// Whether the automatic first guess costs a life and counts as an attempt like any other.
+    /// When it does not, it is still recorded in the history.
+    pub cold_start_counts: bool,
+    /// Keeps the secret away from the values a naive player tries first: both ends of the range
+    /// and its midpoint. Ignored for ranges too small to leave anything else.
+    pub avoid_obvious: bool,
+    /// The chance, from 0.0 to 1.0, that a `TooHigh` or `TooLow` answer is swapped for the
+    /// opposite one. A correct guess is always reported truthfully.
+    ///
+    /// The bounds follow the answers as reported, so they never give a lie away, and after one
+    /// they may no longer hold the secret.
+    pub lie_chance: f64,
+    /// Makes the first guess of the game free: it never costs a life, right or wrong.
+    pub free_first_guess: bool,
+    /// Explains what each result means for the bounds, through [`Game::last_explanation`].
+    pub tutorial: bool,
+    /// Lets hints narrow the bounds without costing a life.
+    pub free_hints: bool,
+    /// The generator that draws the secrets. Whichever kind is chosen, it is seeded from the
+    /// game's own generator, so [`Game::from_seed`] stays reproducible.
+    pub rng_kind: RngKind,
+    /// The most guesses to keep in the history, to bound memory in long-running games.
+    pub history_cap: Option<usize>,
+    /// The lives typed input outside the range costs, to discourage wild guessing. With 0 it is
+    /// rejected for free.
+    pub out_of_range_penalty: u32,
+    /// Caps the score, so tiny ranges with huge lives cannot flood a leaderboard. There is no cap
+    /// by default.
+    pub max_score: Option<u32>,
+    /// Makes finding the secret on the last life a draw, answered with `GuessResult::Draw`, so
+    /// only a win with a life to spare counts. Bonus rounds are not affected.
+    pub require_spare_life: bool,
+    /// Ends a game as lost, answering `GuessResult::MercyLoss`, as soon as a wrong guess leaves it
+    /// hopeless: no longer winnable for sure, and even a perfect player would find the secret
+    /// less often than [`Game::MERCY_CHANCE`]. Spares the player burning the last lives.
+    pub mercy_rule: bool,
+    /// Gives hints a budget of their own: each hint uses one up instead of a life, and none are
+    /// given once it is spent, however many lives are left.
+    pub hint_budget: Option<u32>,
+    /// Tells the player which quarter of the current bounds holds the secret after a wrong guess,
+    /// rather than whether the guess was high or low. The bounds shrink to that quarter.
+    pub quartile_hints: bool,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            min_num: Game::MIN_NUM,
+            max_num: Game::MAX_NUM,
+            lives: Game::LIVES,
+            cold_start: false,
+            cold_start_counts: false,
+            avoid_obvious: false,
+            lie_chance: 0.0,
+            free_first_guess: false,
+            tutorial: false,
+            free_hints: false,
+            rng_kind: RngKind::Std,
+            history_cap: None,
+            out_of_range_penalty: 0,
+            max_score: None,
+            require_spare_life: false,
+            mercy_rule: false,
+            hint_budget: None,
+            quartile_hints: false,
+        }
+    }
+}
+
+/// Represents the result of a single guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GuessResult {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The guess was not played because the game is already over, whether won, lost or drawn.
+    NoMoreLives,
+    Excluded,
+    /// The guess was the secret, but on the last life of a game with `require_spare_life` set.
+    Draw,
+    /// The guess was wrong and left the game hopeless, so the mercy rule ended it as lost.
+    MercyLoss,
+    /// The guess was wrong, and the secret is in this quarter of the bounds before the guess,
+    /// counting from 1 for the lowest quarter to 4 for the highest. Only given with
+    /// `quartile_hints` set.
+    Quartile(u8),
+}
+
+impl GuessResult {
+    /// Returns an emoji summarising the result for compact displays.
+    ///
+    /// Arrows point towards the secret, telling the player which way to go next: a guess that was
+    /// too high shows ⬇️ and a guess that was too low shows ⬆️.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::GuessResult;
+    ///
+    /// assert_eq!(GuessResult::TooHigh.emoji(), "⬇️");
+    /// ```
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            GuessResult::Correct => "🎉",
+            GuessResult::TooHigh => "⬇️",
+            GuessResult::TooLow => "⬆️",
+            GuessResult::NoMoreLives => "💀",
+            GuessResult::Excluded => "🚫",
+            GuessResult::Draw => "🤝",
+            GuessResult::MercyLoss => "🏳️",
+            GuessResult::Quartile(_) => "🧭",
+        }
+    }
+
+    /// Swaps `TooHigh` and `TooLow`, leaving every other result as it is.
+    fn inverted(self) -> Self {
+        match self {
+            GuessResult::TooHigh => GuessResult::TooLow,
+            GuessResult::TooLow => GuessResult::TooHigh,
+            other => other,
+        }
+    }
+}
+
+/// A guess the player made, kept in the game's history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GuessRecord {
+    pub guess: u32,
+    pub result: GuessResult,
+    /// How far the guess was from the secret at the time it was made.
+    pub distance: u32,
+    /// The lower bound after the guess.
+    pub low: u32,
+    /// The upper bound after the guess.
+    pub high: u32,
+    /// The lives left after the guess.
+    pub lives: u32,
+}
+
+/// One step of a replay: the state of a game after a guess, or at the start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplayFrame {
+    /// The guess that led to this frame, or `None` for the starting frame.
+    pub guess: Option<u32>,
+    pub result: Option<GuessResult>,
+    pub low: u32,
+    pub high: u32,
+    pub lives: u32,
+}
+
+/// Represents where a game is in its lifecycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameState {
+    InProgress,
+    Won,
+    Lost,
+    Drawn,
+}
+
+/// Which way a player's guesses lean within the range, as found by [`Game::bias_toward`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Bias {
+    Low,
+    Balanced,
+    High,
+}
+
+/// The player-visible view of a game, which never includes the secret number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PublicState {
+    pub min_num: u32,
+    pub max_num: u32,
+    pub low: u32,
+    pub high: u32,
+    pub lives: u32,
+    pub attempts: u32,
+    pub state: GameState,
+}
+
+/// Represents how a finished game ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameOutcome {
+    Won {
+        attempts: u32,
+        score: u32,
+    },
+    Lost {
+        attempts: u32,
+        secret: u32,
+    },
+    /// The secret was found on the last life when a spare one was required.
+    Drawn {
+        attempts: u32,
+    },
+}
+
+impl GameOutcome {
+    /// Returns how many attempts the game took.
+    pub fn attempts(&self) -> u32 {
+        match *self {
+            GameOutcome::Won { attempts, .. }
+            | GameOutcome::Lost { attempts, .. }
+            | GameOutcome::Drawn { attempts } => attempts,
+        }
+    }
+
+    /// Returns the game's score, which is 0 for a lost or drawn game.
+    pub fn score(&self) -> u32 {
+        match *self {
+            GameOutcome::Won { score, .. } => score,
+            GameOutcome::Lost { .. } | GameOutcome::Drawn { .. } => 0,
+        }
+    }
+
+    /// Returns the share of `population` this game scored strictly better than, from 0.0 to 1.0,
+    /// as in "you did better than 80% of players". An empty population gives 0.0, as there is
+    /// nobody to have beaten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::GameOutcome;
+    ///
+    /// let won = |score| GameOutcome::Won { attempts: 5, score };
+    /// let population = [won(100), won(150), won(200), GameOutcome::Drawn { attempts: 7 }];
+    /// assert_eq!(won(170).percentile_rank(&population), 0.75);
+    /// assert_eq!(won(170).percentile_rank(&[]), 0.0);
+    /// ```
+    pub fn percentile_rank(&self, population: &[GameOutcome]) -> f64 {
+        if population.is_empty() {
+            return 0.0;
+        }
+        let beaten = population
+            .iter()
+            .filter(|other| other.score() < self.score())
+            .count();
+        beaten as f64 / population.len() as f64
+    }
+}
+
+/// How a finished game measured up against a recorded result, from [`Game::compare_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comparison {
+    /// This game's attempts against the record's; `Less` means it took fewer.
+    pub attempts: Ordering,
+    /// This game's score against the record's.
+    pub score: Ordering,
+}
+
+impl Comparison {
+    /// Returns whether the game beat the record: a higher score, or the same score in fewer
+    /// attempts.
+    pub fn beat(&self) -> bool {
+        self.score.then(self.attempts.reverse()) == Ordering::Greater
+    }
+}
+
+/// How useful a guess would be, from [`Game::guess_value`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GuessValue {
+    /// The chance the guess is the secret.
+    pub chance_correct: f64,
+    /// How many possible numbers the guess is expected to rule out if it is wrong.
+    pub expected_reduction: f64,
+    /// The expected information from the answer, in bits: the entropy of correct, too high and
+    /// too low.
+    pub information: f64,
+}
+
+/// Defines the behavior of the number guessing game.
+pub trait GameTrait {
+    /// Runs the number guessing game.
+    ///
+    /// # Arguments
+    ///
+    /// * `guess`: The player's guess.
+    ///
+    /// # Returns
+    ///
+    /// A `GuessResult` indicating the result of the guess.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait, GuessResult};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 7,
+    ///     ..Game::new(Some(1), Some(10), Some(3), &mut rng)
+    /// };
+    /// assert_eq!(game.play(5), GuessResult::TooLow);
+    /// assert_eq!(game.play(7), GuessResult::Correct);
+    /// ```
+    fn play(&mut self, guess: u32) -> GuessResult;
+
+    /// Returns the minimum value for the secret number.
+    fn min_num(&self) -> u32;
+
+    /// Returns the maximum value for the secret number.
+    fn max_num(&self) -> u32;
+
+    /// Returns the number of lives the player has.
+    fn lives(&self) -> u32;
+}
+
+impl GameTrait for Game {
+    fn play(&mut self, guess: u32) -> GuessResult {
+        self.judge(guess, Instant::now())
+    }
+
+    fn min_num(&self) -> u32 {
+        self.min_num
+    }
+
+    fn max_num(&self) -> u32 {
+        self.max_num
+    }
+
+    fn lives(&self) -> u32 {
+        self.lives_at(Instant::now())
+    }
+}
+
+/// The exit code a front end should use when the player quits a game early, matching the shell's
+/// code for a program stopped by Ctrl-C.
+pub const QUIT_EXIT_CODE: i32 = 130;
+
+/// Returns how many guesses a perfect player needs, in the worst case, to find a number among
+/// `candidates` possibilities.
+///
+/// Each guess at the midpoint either wins or halves what is left, so this is `ceil(log2(candidates
+/// + 1))`.
+///
+/// # Examples
+///
+/// ```
+/// use libguess::optimal_guesses;
+///
+/// assert_eq!(optimal_guesses(1), 1);
+/// assert_eq!(optimal_guesses(7), 3);
+/// assert_eq!(optimal_guesses(8), 4);
+/// assert_eq!(optimal_guesses(100), 7);
+/// ```
+pub fn optimal_guesses(candidates: u64) -> u32 {
+    u64::BITS - candidates.leading_zeros()
+}
+
+/// Returns the fewest lives a game over `min..=max` can start with and still be sure of a win for
+/// a perfect player, for picking lives when designing a level.
+///
+/// This is [`optimal_guesses`] for the size of the range, the point at which
+/// [`Game::is_winnable`] first holds: `ceil(log2(size + 1))`. The winning guess costs nothing, but
+/// a perfect player may lose `ceil(log2(size + 1)) - 1` guesses first, and needs one life more
+/// than that to still be playing when the winning guess comes.
+///
+/// # Examples
+///
+/// ```
+/// use libguess::min_lives_to_guarantee;
+///
+/// assert_eq!(min_lives_to_guarantee(1, 100), 7);
+/// assert_eq!(min_lives_to_guarantee(1, 8), 4);
+/// ```
+pub fn min_lives_to_guarantee(min: u32, max: u32) -> u32 {
+    optimal_guesses(u64::from(max.saturating_sub(min)) + 1)
+}
+
+/// Shuffles `(min, max, lives)` puzzle configs into an order fixed by `seed`, so a puzzle app can
+/// serve the same set in a fresh but reproducible order each day.
+///
+/// This is a Fisher-Yates shuffle driven by a `StdRng` seeded like [`Game::from_seed`].
+///
+/// # Examples
+///
+/// ```
+/// use libguess::shuffle_configs;
+///
+/// let mut today = [(1, 10, 4), (1, 100, 7), (1, 1000, 10), (5, 50, 6)];
+/// let mut again = today;
+/// shuffle_configs(&mut today, 20240601);
+/// shuffle_configs(&mut again, 20240601);
+/// assert_eq!(today, again);
+/// ```
+pub fn shuffle_configs(configs: &mut [(u32, u32, u32)], seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    for i in (1..configs.len()).rev() {
+        configs.swap(i, rng.gen_range(0..=i));
+    }
+}
+
+/// Draws the secret of a [`Game::from_seed`] game over `min..=max` for each of `seeds`, for
+/// checking that secrets are spread fairly.
+///
+/// Secrets should be close to uniform over the range: across many seeds their mean should sit
+/// near the midpoint `(min + max) / 2` and their variance near `(n² - 1) / 12` for `n` numbers.
+/// Secrets that bunch up or repeat point to a generator being misused, such as reseeding every
+/// game with the same constant.
+#[cfg(feature = "testing")]
+pub fn sample_secret_positions(min: u32, max: u32, seeds: impl Iterator<Item = u64>) -> Vec<u32> {
+    let config = GameConfig {
+        min_num: min,
+        max_num: max,
+        ..GameConfig::default()
+    };
+    seeds
+        .map(|seed| Game::from_seed(seed, config).secret_number)
+        .collect()
+}
+
+/// Returns the comparison strategy new games start with.
+fn default_strategy() -> Box<dyn CompareStrategy + Send> {
+    Box::new(HighLow)
+}
+
+/// Returns the middle of `low..=high`, rounding down.
+fn midpoint(low: u32, high: u32) -> u32 {
+    low + (high - low) / 2
+}
+
+/// Returns which quarter of `low..=high` holds `secret`, from 1 for the lowest to 4.
+fn quartile_of(low: u32, high: u32, secret: u32) -> u8 {
+    let size = u64::from(high - low) + 1;
+    (4 * u64::from(secret - low) / size) as u8 + 1
+}
+
+/// Returns how a quarter from [`quartile_of`] is named in explanations.
+fn quarter_name(quarter: u8) -> &'static str {
+    match quarter {
+        1 => "lowest",
+        2 => "second",
+        3 => "third",
+        _ => "highest",
+    }
+}
+
+/// Returns the bounds left after a wrong `guess` answered with quarter `quarter` of `low..=high`:
+/// that quarter, as [`quartile_of`] divides it, less the guess if it sits at either end.
+///
+/// The quarter must be the one holding the secret, which is never empty.
+fn quartile_bounds(low: u32, high: u32, quarter: u8, guess: u32) -> (u32, u32) {
+    let size = u64::from(high - low) + 1;
+    let offset = |quarter: u8| (u64::from(quarter) * size + 3) / 4;
+    let start = (u64::from(low) + offset(quarter - 1)) as u32;
+    let end = (u64::from(low) + offset(quarter) - 1) as u32;
+    (
+        if guess == start { start + 1 } else { start },
+        if guess == end { end - 1 } else { end },
+    )
+}
+
+/// Hashes `bytes` with 64-bit FNV-1a, a simple hash whose output never changes.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Draws a secret number uniformly from `min..=max`, skipping the `excluded` values.
+///
+/// `excluded` must be sorted, free of duplicates and within the range, and must leave at least
+/// one number to draw.
+fn draw_secret<R: Rng + ?Sized>(rng: &mut R, min: u32, max: u32, excluded: &[u32]) -> u32 {
+    if excluded.is_empty() {
+        return rng.gen_range(min..=max);
+    }
+
+    let allowed = u64::from(max - min) + 1 - excluded.len() as u64;
+    let mut secret = u64::from(min) + rng.gen_range(0..allowed);
+    for &n in excluded {
+        if u64::from(n) <= secret {
+            secret += 1;
+        }
+    }
+    secret as u32
+}
+
+/// Performs the comparison between a guess and the secret number.
+///
+/// # Arguments
+///
+/// * `guess`: The player's guess.
+/// * `secret`: The secret number to compare against.
+///
+/// # Returns
+///
+/// A `GuessResult` indicating whether the guess is correct, too high, or too low.
+///
+/// # Examples
+///
+/// ```
+/// use libguess::compare;
+/// use libguess::GuessResult;
+///
+/// assert_eq!(compare(5, 5), GuessResult::Correct);
+/// assert_eq!(compare(4, 5), GuessResult::TooLow);
+/// assert_eq!(compare(6, 5), GuessResult::TooHigh);
+/// ```
+pub fn compare(guess: u32, secret: u32) -> GuessResult {
+    match guess.cmp(&secret) {
+        Ordering::Equal => GuessResult::Correct,
+        Ordering::Less => GuessResult::TooLow,
+        Ordering::Greater => GuessResult::TooHigh,
+    }
+}
+
+/// Reads a guess typed by the player, ignoring surrounding whitespace, and checks it lies in
+/// `min..=max`.
+///
+/// # Errors
+///
+/// Returns `GuessInputError::Empty` for blank input, `GuessInputError::NotANumber` for anything
+/// that is not a whole number, and `GuessInputError::OutOfRange` for a number outside the range.
+///
+/// # Examples
+///
+/// ```
+/// use libguess::{parse_guess, GuessInputError};
+///
+/// assert_eq!(parse_guess(" 12 ", 1, 20), Ok(12));
+/// assert_eq!(parse_guess("   ", 1, 20), Err(GuessInputError::Empty));
+/// assert_eq!(parse_guess("25", 1, 20), Err(GuessInputError::OutOfRange { min: 1, max: 20 }));
+/// ```
+pub fn parse_guess(input: &str, min: u32, max: u32) -> Result<u32, GuessInputError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(GuessInputError::Empty);
+    }
+    if !input.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(GuessInputError::NotANumber);
+    }
+    match input.parse() {
+        Ok(guess) if (min..=max).contains(&guess) => Ok(guess),
+        _ => Err(GuessInputError::OutOfRange { min, max }),
+    }
+}
+
+/// Compares a guess with the secret number like [`compare`], also returning how far apart they
+/// are.
+///
+/// # Examples
+///
+/// ```
+/// use libguess::{compare_verbose, GuessResult};
+///
+/// assert_eq!(compare_verbose(5, 5), (GuessResult::Correct, 0));
+/// assert_eq!(compare_verbose(2, 5), (GuessResult::TooLow, 3));
+/// ```
+pub fn compare_verbose(guess: u32, secret: u32) -> (GuessResult, u32) {
+    (compare(guess, secret), guess.abs_diff(secret))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_game() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let game = Game::new(Some(1), Some(10), Some(5), &mut rng);
+        assert_eq!(game.min_num(), 1);
+        assert_eq!(game.max_num(), 10);
+        assert_eq!(game.lives(), 5);
+
+        let mut rng = StdRng::from_seed(Default::default());
+        let default_game = Game::new(None, None, None, &mut rng);
+        assert_eq!(default_game.min_num(), Game::MIN_NUM);
+        assert_eq!(default_game.max_num(), Game::MAX_NUM);
+        assert_eq!(default_game.lives(), Game::LIVES);
+    }
+
+    #[test]
+    fn test_play() {
+        let secret_number = 3;
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number,
+            ..Game::new(Some(1), Some(10), Some(10), &mut rng)
+        };
+
+        for _ in 0..8 {
+            assert_eq!(game.play(1), GuessResult::TooLow);
+        }
+        assert_eq!(game.play(20), GuessResult::TooHigh);
+        assert_eq!(game.play(3), GuessResult::Correct);
+    }
+
+    #[test]
+    fn test_guesses_after_end_ignored() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 7,
+            ..Game::new(Some(1), Some(100), Some(7), &mut rng)
+        };
+        game.play(50);
+        assert_eq!(game.play(7), GuessResult::Correct);
+        // A won game used to go on comparing guesses, answering this one with TooLow.
+        assert_eq!(game.play(1), GuessResult::NoMoreLives);
+        let score = game.score();
+        let outcome = game.outcome();
+        for guess in [7, 1, 100] {
+            assert_eq!(game.play(guess), GuessResult::NoMoreLives);
+        }
+        assert_eq!(game.attempts, 2);
+        assert_eq!(game.lives(), 6);
+        assert_eq!(game.history.len(), 2);
+        assert_eq!(game.score(), score);
+        assert_eq!(game.outcome(), outcome);
+    }
+
+    #[test]
+    fn test_compare() {
+        let comparisons = [
+            (5, 5, GuessResult::Correct),
+            (4, 5, GuessResult::TooLow),
+            (6, 5, GuessResult::TooHigh),
+        ];
+        for (guess, secret, result) in comparisons {
+            assert_eq!(compare(guess, secret), result);
+        }
+    }
+
+    #[test]
+    fn test_compare_verbose() {
+        let comparisons = [
+            (5, 5, GuessResult::Correct, 0),
+            (1, 5, GuessResult::TooLow, 4),
+            (12, 5, GuessResult::TooHigh, 7),
+            (0, u32::MAX, GuessResult::TooLow, u32::MAX),
+        ];
+        for (guess, secret, result, distance) in comparisons {
+            assert_eq!(compare_verbose(guess, secret), (result, distance));
+        }
+    }
+
+    #[test]
+    fn test_bounds_phrase() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 9,
+            ..Game::new(Some(1), Some(12), Some(5), &mut rng)
+        };
+        assert_eq!(game.play(5), GuessResult::TooLow);
+        assert_eq!(game.bounds(), (6, 12));
+        assert_eq!(
+            game.bounds_phrase(),
+            "The number is between 6 and 12, 7 possibilities remaining."
+        );
+
+        assert_eq!(game.play(10), GuessResult::TooHigh);
+        assert_eq!(game.play(8), GuessResult::TooLow);
+        assert_eq!(game.possible_count(), 1);
+        assert_eq!(
+            game.bounds_phrase(),
+            "The number is 9, 1 possibility remaining."
+        );
+    }
+
+    #[test]
+    fn test_outcome() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 4,
+            ..Game::new(Some(1), Some(10), Some(2), &mut rng)
+        };
+        assert_eq!(game.outcome(), None);
+        assert_eq!(game.play(2), GuessResult::TooLow);
+        assert_eq!(game.play(4), GuessResult::Correct);
+        assert_eq!(
+            game.outcome(),
+            Some(GameOutcome::Won {
+                attempts: 2,
+                score: 100
+            })
+        );
+
+        let mut game = Game {
+            secret_number: 4,
+            ..Game::new(Some(1), Some(10), Some(1), &mut rng)
+        };
+        assert_eq!(game.play(9), GuessResult::TooHigh);
+        assert_eq!(
+            game.outcome(),
+            Some(GameOutcome::Lost {
+                attempts: 1,
+                secret: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_bonus_mode() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 6,
+            bonus_mode: true,
+            ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+        };
+        assert_eq!(game.play(3), GuessResult::TooLow);
+        let expected_secret = game.rng.clone().gen_range(1..=10);
+        assert_eq!(game.play(6), GuessResult::Correct);
+        assert_eq!(game.secret_number, expected_secret);
+        assert_eq!(game.lives(), 4);
+        assert_eq!(game.bounds(), (1, 10));
+        assert_eq!(game.outcome(), None);
+
+        let secret = game.secret_number;
+        assert_eq!(game.play(secret), GuessResult::Correct);
+        assert_eq!(game.bonus_count, 1);
+        assert_eq!(game.lives(), 4);
+        assert_eq!(game.score(), 100 * 5 / 3 + Game::BONUS_POINTS);
+    }
+
+    #[test]
+    fn test_correct_probability() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 12,
+            ..Game::new(Some(1), Some(20), Some(5), &mut rng)
+        };
+        assert_eq!(game.play(10), GuessResult::TooLow);
+        assert_eq!(game.play(15), GuessResult::TooHigh);
+        assert!((game.correct_probability(12) - 0.25).abs() < f64::EPSILON);
+        assert!(game.correct_probability(5).abs() < f64::EPSILON);
+
+        assert_eq!(game.play(13), GuessResult::TooHigh);
+        assert_eq!(game.play(11), GuessResult::TooLow);
+        assert!((game.correct_probability(12) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[cfg(feature = "logging")]
+    #[test]
+    fn test_logging() {
+        use std::sync::Mutex;
+
+        struct CaptureLogger {
+            records: Mutex<Vec<(log::Level, String)>>,
+        }
+
+        impl log::Log for CaptureLogger {
+            fn enabled(&self, _: &log::Metadata) -> bool {
+                true
+            }
+
+            fn log(&self, record: &log::Record) {
+                self.records
+                    .lock()
+                    .unwrap()
+                    .push((record.level(), record.args().to_string()));
+            }
+
+            fn flush(&self) {}
+        }
+
+        let logger: &'static CaptureLogger = Box::leak(Box::new(CaptureLogger {
+            records: Mutex::new(Vec::new()),
+        }));
+        log::set_logger(logger).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let secret = 987_654;
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: secret,
+            ..Game::new(Some(900_000), Some(999_999), Some(5), &mut rng)
+        };
+        game.play(950_000);
+        game.play(secret);
+
+        let records = logger.records.lock().unwrap();
+        assert!(records
+            .iter()
+            .any(|(level, msg)| *level == log::Level::Debug
+                && msg.contains("guess 950000 was TooLow")));
+        assert!(records
+            .iter()
+            .filter(|(level, _)| *level < log::Level::Trace)
+            .all(|(_, msg)| !msg.contains("987654") || msg.contains("guess 987654")));
+    }
+
+    #[test]
+    fn test_hint_half() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 8,
+            ..Game::new(Some(1), Some(10), Some(2), &mut rng)
+        };
+        assert_eq!(game.hint_half(), Some(false));
+        assert_eq!(game.bounds(), (6, 10));
+        assert_eq!(game.lives(), 1);
+
+        assert_eq!(game.hint_half(), Some(true));
+        assert_eq!(game.bounds(), (6, 8));
+        assert_eq!(game.lives(), 0);
+
+        assert_eq!(game.hint_half(), None);
+        assert_eq!(game.bounds(), (6, 8));
+
+        let mut won = Game {
+            secret_number: 8,
+            ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+        };
+        won.play(8);
+        assert_eq!(won.hints_remaining(), 0);
+        assert_eq!(won.hint_half(), None);
+        assert_eq!(won.hint_bit(0), None);
+        assert_eq!(won.lives(), 5);
+        assert_eq!(won.bounds(), (8, 8));
+    }
+
+    #[test]
+    fn test_hint_bit() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 0b1011_0110,
+            ..Game::new(Some(1), Some(200), Some(10), &mut rng)
+        };
+        for index in 0..8 {
+            let lives = game.lives();
+            assert_eq!(
+                game.hint_bit(index),
+                Some(game.secret_number >> index & 1 == 1)
+            );
+            assert_eq!(game.lives(), lives - 1);
+        }
+        assert_eq!(game.hint_bit(8), None);
+        assert_eq!(game.hint_bit(u32::MAX), None);
+        assert_eq!(game.lives(), 2);
+        assert_eq!(game.bounds(), (1, 200));
+
+        game.free_hints = true;
+        assert_eq!(game.hint_bit(1), Some(true));
+        assert_eq!(game.lives(), 2);
+
+        game.free_hints = false;
+        game.hint_bit(0);
+        game.hint_bit(0);
+        assert_eq!(game.hint_bit(0), None);
+
+        let mut announced = Game {
+            secret_number: 6,
+            ..Game::new(Some(1), Some(10), Some(10), &mut rng)
+        };
+        announced.with_announced_range(1, 100).unwrap();
+        assert_eq!(announced.hint_bit(4), Some(false));
+        assert_eq!(announced.hint_bit(6), Some(false));
+        assert_eq!(announced.hint_bit(7), None);
+        assert_eq!(announced.lives(), 8);
+    }
+
+    #[test]
+    fn test_answer_is_determined() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 9,
+            ..Game::new(Some(1), Some(12), Some(5), &mut rng)
+        };
+        assert!(!game.answer_is_determined());
+        assert_eq!(game.play(10), GuessResult::TooHigh);
+        assert_eq!(game.play(7), GuessResult::TooLow);
+        assert!(!game.answer_is_determined());
+        assert_eq!(game.play(8), GuessResult::TooLow);
+        assert!(game.answer_is_determined());
+        assert_eq!(game.state(), GameState::InProgress);
+
+        let mut excluded = Game {
+            secret_number: 9,
+            excluded: vec![8, 10],
+            ..Game::new(Some(1), Some(12), Some(5), &mut rng)
+        };
+        assert_eq!(excluded.play(7), GuessResult::TooLow);
+        assert_eq!(excluded.play(11), GuessResult::TooHigh);
+        assert!(excluded.answer_is_determined());
+
+        let mut lost = Game {
+            secret_number: 9,
+            ..Game::new(Some(1), Some(12), Some(2), &mut rng)
+        };
+        lost.play(1);
+        lost.play(2);
+        assert!(lost.is_over());
+        assert!(!lost.answer_is_determined());
+    }
+
+    #[test]
+    fn test_bounds_after() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 70,
+            ..Game::new(Some(1), Some(100), Some(5), &mut rng)
+        };
+        let predicted = game.bounds_after(49);
+        assert_eq!(game.lives(), 5);
+        assert_eq!(game.play(49), GuessResult::TooLow);
+        assert_eq!(game.bounds(), predicted);
+
+        let predicted = game.bounds_after(90);
+        assert_eq!(game.play(90), GuessResult::TooHigh);
+        assert_eq!(game.bounds(), predicted);
+
+        assert_eq!(game.bounds_after(10), (50, 89));
+    }
+
+    #[test]
+    fn test_life_regeneration() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 5,
+            regen_cooldown: Some(Duration::from_secs(60)),
+            ..Game::new(Some(1), Some(10), Some(3), &mut rng)
+        };
+        assert_eq!(game.play(1), GuessResult::TooLow);
+        assert_eq!(game.play(9), GuessResult::TooHigh);
+
+        let now = Instant::now();
+        assert_eq!(game.lives_at(now), 1);
+        assert_eq!(game.lives_at(now + Duration::from_secs(30)), 1);
+        assert_eq!(game.lives_at(now + Duration::from_secs(60)), 3);
+        assert_eq!(game.lives_at(now + Duration::from_secs(3600)), 3);
+
+        game.settle_regen(now + Duration::from_secs(60));
+        assert_eq!(game.lives, 3);
+        assert!(game.lost_at.is_empty());
+        assert_eq!(game.lives_at(now + Duration::from_secs(3600)), 3);
+    }
+
+    #[test]
+    fn test_no_regeneration_after_loss() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 5,
+            regen_cooldown: Some(Duration::from_secs(10)),
+            ..Game::new(Some(1), Some(10), Some(2), &mut rng)
+        };
+        let start = Instant::now();
+        game.play_at(1, start);
+        game.play_at(9, start);
+        let lost = Some(GameOutcome::Lost {
+            attempts: 2,
+            secret: 5,
+        });
+        assert_eq!(game.outcome(), lost);
+
+        let later = start + Duration::from_secs(11);
+        assert_eq!(game.lives_at(later), 0);
+        assert_eq!(game.play_at(5, later), GuessResult::NoMoreLives);
+        assert_eq!(game.outcome(), lost);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_reader_writer_round_trip() {
+        use std::io::Cursor;
+
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 13,
+            ..Game::new(Some(1), Some(20), Some(5), &mut rng)
+        };
+        assert_eq!(game.play(8), GuessResult::TooLow);
+
+        let mut buffer = Cursor::new(Vec::new());
+        game.to_writer(&mut buffer).unwrap();
+        buffer.set_position(0);
+        let mut loaded = Game::from_reader(buffer).unwrap();
+
+        assert_eq!(loaded.public_state(), game.public_state());
+        assert_eq!(loaded.secret_number, 13);
+        assert_eq!(loaded.play(13), GuessResult::Correct);
+    }
+
+    #[test]
+    fn test_missed_wins() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 12,
+            ..Game::new(Some(1), Some(20), Some(10), &mut rng)
+        };
+        for guess in [5, 13, 9, 11, 12] {
+            game.play(guess);
+        }
+        assert_eq!(game.history.len(), 5);
+        assert_eq!(game.missed_wins(), 2);
+    }
+
+    #[test]
+    fn test_emoji() {
+        let emojis = [
+            (GuessResult::Correct, "🎉"),
+            (GuessResult::TooHigh, "⬇️"),
+            (GuessResult::TooLow, "⬆️"),
+            (GuessResult::NoMoreLives, "💀"),
+            (GuessResult::Excluded, "🚫"),
+            (GuessResult::MercyLoss, "🏳️"),
+            (GuessResult::Quartile(2), "🧭"),
+        ];
+        for (result, emoji) in emojis {
+            assert_eq!(result.emoji(), emoji);
+        }
+    }
+
+    #[test]
+    fn test_new_excluding() {
+        let exclude = [10, 20, 30, 40, 50, 0, 10];
+        for seed in 0..500 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let game = Game::new_excluding(Some(1), Some(50), None, &exclude, &mut rng).unwrap();
+            assert!(!exclude.contains(&game.secret_number));
+            assert!((1..=50).contains(&game.secret_number));
+        }
+
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game::new_excluding(Some(1), Some(5), Some(3), &[2, 4], &mut rng).unwrap();
+        assert_eq!(game.excluded, vec![2, 4]);
+        assert_eq!(game.possible_count(), 3);
+        assert_eq!(game.play(4), GuessResult::Excluded);
+        assert_eq!(game.lives(), 3);
+        assert_eq!(game.attempts, 0);
+
+        assert_eq!(
+            Game::new_excluding(Some(1), Some(3), None, &[1, 2, 3], &mut rng).err(),
+            Some(GameError::AllExcluded)
+        );
+        assert_eq!(
+            Game::new_excluding(Some(5), Some(3), None, &[], &mut rng).err(),
+            Some(GameError::InvalidRange)
+        );
+    }
+
+    #[test]
+    fn test_from_seed_str() {
+        let config = GameConfig {
+            min_num: 1,
+            max_num: 1_000_000,
+            lives: 20,
+            ..GameConfig::default()
+        };
+        let dragon = Game::from_seed_str("dragon", config);
+        assert_eq!(
+            dragon.secret_number,
+            Game::from_seed_str("dragon", config).secret_number
+        );
+        assert_eq!(dragon.seed, Some(fnv1a(b"dragon")));
+        assert_eq!(fnv1a(b"dragon"), 0x7104_93a2_1d69_315e);
+
+        let phrases = ["griffin", "wyvern", "basilisk", "hydra"];
+        let differing = phrases
+            .iter()
+            .filter(|&&phrase| {
+                Game::from_seed_str(phrase, config).secret_number != dragon.secret_number
+            })
+            .count();
+        assert!(differing >= phrases.len() - 1);
+    }
+
+    #[test]
+    fn test_optimal_solution() {
+        for secret in 1..=100 {
+            let mut rng = StdRng::from_seed(Default::default());
+            let mut game = Game {
+                secret_number: secret,
+                ..Game::new(Some(1), Some(100), Some(1), &mut rng)
+            };
+            game.play(if secret == 1 { 2 } else { 1 });
+            let solution = game.optimal_solution().unwrap();
+            assert!(solution.len() <= 7);
+
+            let mut fresh = Game {
+                secret_number: secret,
+                ..Game::new(Some(1), Some(100), Some(7), &mut rng)
+            };
+            let results: Vec<_> = solution.iter().map(|&guess| fresh.play(guess)).collect();
+            assert_eq!(results.last(), Some(&GuessResult::Correct));
+            assert_eq!(fresh.state(), GameState::Won);
+        }
+    }
+
+    #[test]
+    fn test_optimal_solution_skips_excluded() {
+        let mut rng = StdRng::from_seed(Default::default());
+        for secret in (1..=20).filter(|secret| ![5, 10, 11, 15].contains(secret)) {
+            let mut game = Game {
+                secret_number: secret,
+                excluded: vec![5, 10, 11, 15],
+                ..Game::new(Some(1), Some(20), Some(1), &mut rng)
+            };
+            game.give_up();
+            let solution = game.optimal_solution().unwrap();
+            assert!(solution.iter().all(|guess| !game.excluded.contains(guess)));
+            assert_eq!(solution.last(), Some(&secret));
+        }
+    }
+
+    #[test]
+    fn test_is_winnable() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut wasteful = Game {
+            secret_number: 50,
+            ..Game::new(Some(1), Some(100), Some(7), &mut rng)
+        };
+        assert!(wasteful.is_winnable());
+        assert_eq!(wasteful.play(1), GuessResult::TooLow);
+        assert!(!wasteful.is_winnable());
+
+        let mut careful = Game {
+            secret_number: 80,
+            ..Game::new(Some(1), Some(100), Some(7), &mut rng)
+        };
+        assert_eq!(careful.play(50), GuessResult::TooLow);
+        assert!(careful.is_winnable());
+    }
+
+    #[test]
+    fn test_min_lives_to_guarantee() {
+        for (min, max, lives) in [
+            (1, 1, 1),
+            (1, 2, 2),
+            (1, 4, 3),
+            (1, 7, 3),
+            (1, 8, 4),
+            (0, 15, 5),
+            (1, 100, 7),
+            (1, 1024, 11),
+            (1, 1000, 10),
+            (0, u32::MAX, 33),
+        ] {
+            assert_eq!(min_lives_to_guarantee(min, max), lives, "{min}..={max}");
+        }
+
+        let mut rng = StdRng::from_seed(Default::default());
+        for max in [2, 8, 100, 1000] {
+            let lives = min_lives_to_guarantee(1, max);
+            assert!(Game::new(Some(1), Some(max), Some(lives), &mut rng).is_winnable());
+            assert!(!Game::new(Some(1), Some(max), Some(lives - 1), &mut rng).is_winnable());
+        }
+    }
+
+    #[test]
+    fn test_shuffle_configs() {
+        let configs: Vec<(u32, u32, u32)> = (1..=10).map(|i| (1, i * 10, i)).collect();
+        let shuffled = |seed| {
+            let mut order = configs.clone();
+            shuffle_configs(&mut order, seed);
+            order
+        };
+
+        assert_eq!(shuffled(7), shuffled(7));
+        let mut sorted = shuffled(7);
+        sorted.sort_unstable();
+        assert_eq!(sorted, configs);
+
+        let orders: Vec<_> = (0..20).map(shuffled).collect();
+        let distinct = orders
+            .iter()
+            .enumerate()
+            .filter(|(i, order)| !orders[..*i].contains(order))
+            .count();
+        assert!(distinct >= 19, "only {distinct} distinct orders");
+
+        let mut empty: [(u32, u32, u32); 0] = [];
+        shuffle_configs(&mut empty, 1);
+        let mut single = [(1, 20, 5)];
+        shuffle_configs(&mut single, 1);
+        assert_eq!(single, [(1, 20, 5)]);
+    }
+
+    #[test]
+    fn test_compare_strategy() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut standard = Game {
+            secret_number: 10,
+            ..Game::new(Some(1), Some(20), Some(5), &mut rng)
+        };
+        let mut reversed = Game {
+            secret_number: 10,
+            strategy: Box::new(Reversed),
+            ..Game::new(Some(1), Some(20), Some(5), &mut rng)
+        };
+
+        assert_eq!(standard.play(15), GuessResult::TooHigh);
+        assert_eq!(reversed.play(15), GuessResult::TooLow);
+        assert_eq!(reversed.bounds(), standard.bounds());
+        assert_eq!(reversed.lives(), 4);
+        assert_eq!(reversed.play(10), GuessResult::Correct);
+    }
+
+    #[test]
+    fn test_forfeit() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 12,
+            ..Game::new(Some(1), Some(20), Some(10), &mut rng)
+        };
+        game.play(5);
+        game.play(15);
+        assert_eq!(
+            game.forfeit(),
+            GameOutcome::Lost {
+                attempts: 2,
+                secret: 12
+            }
+        );
+        assert_eq!(game.state(), GameState::Lost);
+        assert_eq!(game.play(12), GuessResult::NoMoreLives);
+
+        let mut won = Game {
+            secret_number: 12,
+            ..Game::new(Some(1), Some(20), Some(10), &mut rng)
+        };
+        won.play(12);
+        assert_eq!(
+            won.forfeit(),
+            GameOutcome::Won {
+                attempts: 1,
+                score: 1100
+            }
+        );
+    }
+
+    #[test]
+    fn test_with_head_start() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 30,
+            ..Game::new(Some(1), Some(100), Some(7), &mut rng)
+        };
+        assert_eq!(
+            game.with_head_start(31, 60),
+            Err(GameError::HeadStartExcludesSecret)
+        );
+        assert_eq!(game.with_head_start(0, 60), Err(GameError::InvalidRange));
+        assert_eq!(game.with_head_start(60, 20), Err(GameError::InvalidRange));
+        assert_eq!(game.bounds(), (1, 100));
+
+        assert_eq!(game.with_head_start(21, 60), Ok(()));
+        assert_eq!(game.bounds(), (21, 60));
+        assert_eq!(game.possible_count(), 40);
+        assert_eq!(game.hint_half(), Some(true));
+        assert_eq!(game.bounds(), (21, 40));
+        assert_eq!(game.min_num(), 1);
+
+        game.play(25);
+        assert_eq!(game.with_head_start(1, 35), Ok(()));
+        assert_eq!(game.bounds(), (26, 35));
+
+        let mut announced = Game {
+            secret_number: 30,
+            ..Game::new(Some(20), Some(80), Some(7), &mut rng)
+        };
+        announced.with_announced_range(1, 100).unwrap();
+        assert_eq!(announced.with_head_start(10, 90), Ok(()));
+        assert_eq!(announced.bounds(), (10, 90));
+        assert_eq!(
+            announced.with_head_start(0, 90),
+            Err(GameError::InvalidRange)
+        );
+    }
+
+    #[test]
+    fn test_rng_mut() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 5,
+            bonus_mode: true,
+            ..Game::new(Some(1), Some(1000), Some(5), &mut rng)
+        };
+        let mut expected = game.rng().clone();
+        let external: u32 = game.rng_mut().gen();
+        assert_eq!(external, expected.gen::<u32>());
+
+        game.play(5);
+        assert_eq!(game.secret_number, expected.gen_range(1..=1000));
+    }
+
+    #[test]
+    fn test_weighted_secret_respects_exclusions() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            excluded: vec![4, 6, 7],
+            avoid_obvious: true,
+            ..Game::new_with_distribution(
+                Some(1),
+                Some(10),
+                Some(7),
+                SecretDistribution::Triangular,
+                &mut rng,
+            )
+        };
+        let avoided = game.secret_exclusions();
+        assert_eq!(avoided, [1, 4, 5, 6, 7, 10]);
+        for _ in 0..1000 {
+            assert!(!avoided.contains(&game.draw_round_secret()));
+        }
+    }
+
+    #[test]
+    fn test_secret_surprisal() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut uniform = Game::new(Some(1), Some(100), Some(7), &mut rng);
+        uniform.give_up();
+        assert!((uniform.secret_surprisal().unwrap() - 100f64.log2()).abs() < 1e-9);
+
+        let mut middle = Game {
+            secret_number: 50,
+            ..Game::new_with_distribution(
+                Some(1),
+                Some(100),
+                Some(7),
+                SecretDistribution::Triangular,
+                &mut rng,
+            )
+        };
+        let mut edge = Game {
+            secret_number: 1,
+            ..Game::new_with_distribution(
+                Some(1),
+                Some(100),
+                Some(7),
+                SecretDistribution::Triangular,
+                &mut rng,
+            )
+        };
+        assert_eq!(middle.secret_surprisal(), None);
+        middle.give_up();
+        edge.give_up();
+
+        let middle_bits = middle.secret_surprisal().unwrap();
+        let edge_bits = edge.secret_surprisal().unwrap();
+        assert!(middle_bits < 100f64.log2());
+        assert!(edge_bits > middle_bits);
+        assert!((edge_bits - (10_000f64 / 3.0).log2()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cold_start() {
+        let config = GameConfig {
+            min_num: 1,
+            max_num: 100,
+            lives: 7,
+            cold_start: true,
+            ..GameConfig::default()
+        };
+        let free = Game::from_seed(3, config);
+        assert_ne!(free.secret_number, 50);
+        let expected = if free.secret_number < 50 {
+            (1, 49)
+        } else {
+            (51, 100)
+        };
+        assert_eq!(free.bounds(), expected);
+        assert_eq!(free.history.len(), 1);
+        assert_eq!(free.history[0].guess, 50);
+        assert_eq!(free.attempts, 0);
+        assert_eq!(free.lives(), 7);
+
+        let counted = Game::from_seed(
+            3,
+            GameConfig {
+                cold_start_counts: true,
+                ..config
+            },
+        );
+        assert_eq!(counted.bounds(), free.bounds());
+        assert_eq!(counted.attempts, 1);
+        assert_eq!(counted.lives(), 6);
+    }
+
+    #[test]
+    fn test_replay_frames() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 30,
+            ..Game::new(Some(1), Some(100), Some(7), &mut rng)
+        };
+        let guesses = [50, 25, 37, 30];
+        let results: Vec<_> = guesses.iter().map(|&guess| game.play(guess)).collect();
+
+        let frames = game.replay_frames();
+        assert_eq!(frames.len(), guesses.len() + 1);
+        assert_eq!(frames[0].guess, None);
+        assert_eq!(frames[0].lives, 7);
+        let replayed: Vec<_> = frames[1..]
+            .iter()
+            .map(|frame| frame.guess.unwrap())
+            .collect();
+        assert_eq!(replayed, guesses);
+        let replayed: Vec<_> = frames[1..]
+            .iter()
+            .map(|frame| frame.result.unwrap())
+            .collect();
+        assert_eq!(replayed, results);
+        assert_eq!(
+            (frames[2].low, frames[2].high, frames[2].lives),
+            (26, 49, 5)
+        );
+        assert_eq!((frames[4].low, frames[4].high), (30, 30));
+    }
+
+    #[test]
+    fn test_avoid_obvious() {
+        let config = GameConfig {
+            min_num: 1,
+            max_num: 9,
+            avoid_obvious: true,
+            ..GameConfig::default()
+        };
+        for seed in 0..500 {
+            let game = Game::from_seed(seed, config);
+            assert!(game.avoid_obvious);
+            assert!(![1, 5, 9].contains(&game.secret_number));
+        }
+
+        let tiny = Game::from_seed(
+            0,
+            GameConfig {
+                max_num: 3,
+                ..config
+            },
+        );
+        assert!(!tiny.avoid_obvious);
+        assert!(!Game::from_seed(0, GameConfig::default()).avoid_obvious);
+    }
+
+    #[test]
+    fn test_guess_timestamps() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 8,
+            ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+        };
+        let start = Instant::now();
+        let times = [
+            start,
+            start + Duration::from_secs(3),
+            start + Duration::from_secs(10),
+        ];
+        game.play_at(2, times[0]);
+        game.play(4);
+        game.play_at(9, times[1]);
+        game.play_at(8, times[2]);
+
+        assert_eq!(game.history.len(), 4);
+        assert_eq!(game.guess_timestamps(), times);
+    }
+
+    #[test]
+    fn test_average_think_time() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 50,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        assert_eq!(game.average_think_time(), None);
+
+        let start = Instant::now();
+        for (i, guess) in [10, 20, 30, 40, 50].into_iter().enumerate() {
+            game.play_at(guess, start + Duration::from_millis(1500) * i as u32);
+        }
+        assert_eq!(game.average_think_time(), Some(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn test_mercy_rule() {
+        let game = |lives, mercy_rule| {
+            let config = GameConfig {
+                min_num: 1,
+                max_num: 100,
+                lives,
+                mercy_rule,
+                ..GameConfig::default()
+            };
+            Game {
+                secret_number: 80,
+                ..Game::from_seed(1, config)
+            }
+        };
+
+        let mut hopeless = game(3, true);
+        assert_eq!(hopeless.play(1), GuessResult::MercyLoss);
+        assert_eq!(hopeless.state(), GameState::Lost);
+        assert_eq!(hopeless.lives(), 0);
+        assert_eq!(
+            hopeless.outcome(),
+            Some(GameOutcome::Lost {
+                attempts: 1,
+                secret: 80
+            })
+        );
+        assert_eq!(hopeless.history[0].result, GuessResult::MercyLoss);
+        assert_eq!(hopeless.play(80), GuessResult::NoMoreLives);
+
+        let mut grinding = game(3, false);
+        assert_eq!(grinding.play(1), GuessResult::TooLow);
+        assert_eq!(grinding.play(2), GuessResult::TooLow);
+        assert_eq!(grinding.play(3), GuessResult::TooLow);
+        assert_eq!(grinding.play(80), GuessResult::NoMoreLives);
+
+        let mut unlucky = game(6, true);
+        assert!(!unlucky.is_winnable());
+        assert_eq!(unlucky.play(50), GuessResult::TooLow);
+        assert_eq!(unlucky.state(), GameState::InProgress);
+        assert_eq!(unlucky.play(51), GuessResult::TooLow);
+        assert_eq!(unlucky.play(52), GuessResult::TooLow);
+        assert_eq!(unlucky.play(53), GuessResult::MercyLoss);
+
+        let mut last_life = game(1, true);
+        assert_eq!(last_life.play(1), GuessResult::TooLow);
+        assert_eq!(last_life.state(), GameState::Lost);
+    }
+
+    #[test]
+    fn test_quartile_hints() {
+        let config = GameConfig {
+            min_num: 1,
+            max_num: 100,
+            quartile_hints: true,
+            ..GameConfig::default()
+        };
+        let mut game = Game {
+            secret_number: 80,
+            ..Game::from_seed(1, config)
+        };
+        assert_eq!(game.play(50), GuessResult::Quartile(4));
+        assert_eq!(game.bounds(), (76, 100));
+        assert_eq!(game.play(90), GuessResult::Quartile(1));
+        assert_eq!(game.bounds(), (76, 82));
+        assert_eq!(game.play(76), GuessResult::Quartile(3));
+        assert_eq!(game.bounds(), (80, 81));
+        assert_eq!(game.lives(), Game::LIVES - 3);
+        assert_eq!(game.play(80), GuessResult::Correct);
+
+        for size in 1..=9 {
+            for secret in 1..=size {
+                for guess in (1..=size).filter(|&guess| guess != secret) {
+                    let config = GameConfig {
+                        min_num: 1,
+                        max_num: size,
+                        quartile_hints: true,
+                        ..GameConfig::default()
+                    };
+                    let mut game = Game {
+                        secret_number: secret,
+                        ..Game::from_seed(1, config)
+                    };
+                    let quarter = match game.play(guess) {
+                        GuessResult::Quartile(quarter) => quarter,
+                        result => panic!("{result:?} for {guess} with secret {secret}"),
+                    };
+                    let position = f64::from(secret - 1) / f64::from(size);
+                    assert_eq!(f64::from(quarter - 1), (position * 4.0).floor());
+                    let (low, high) = game.bounds();
+                    assert!((low..=high).contains(&secret));
+                    assert!(guess != low && guess != high);
+                    assert!(f64::from(low - 1) / f64::from(size) >= f64::from(quarter - 1) / 4.0);
+                    assert!(f64::from(high - 1) / f64::from(size) < f64::from(quarter) / 4.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_require_spare_life() {
+        let last_life = |require_spare_life| {
+            let config = GameConfig {
+                min_num: 1,
+                max_num: 10,
+                lives: 2,
+                require_spare_life,
+                ..GameConfig::default()
+            };
+            let mut game = Game::from_seed(3, config);
+            let wrong = if game.secret_number == 1 { 2 } else { 1 };
+            game.play(wrong);
+            assert_eq!(game.lives(), 1);
+            let result = game.play(game.secret_number);
+            (game, result)
+        };
+
+        let (game, result) = last_life(false);
+        assert_eq!(result, GuessResult::Correct);
+        assert_eq!(game.state(), GameState::Won);
+
+        let (game, result) = last_life(true);
+        assert_eq!(result, GuessResult::Draw);
+        assert_eq!(game.state(), GameState::Drawn);
+        assert_eq!(game.outcome(), Some(GameOutcome::Drawn { attempts: 2 }));
+        assert_eq!(game.score(), 0);
+        assert_eq!(game.lives(), 1);
+        assert_eq!(game.bounds(), (game.secret_number, game.secret_number));
+        assert_eq!(game.history[1].result, GuessResult::Draw);
+
+        let mut spare = Game::from_seed(
+            3,
+            GameConfig {
+                require_spare_life: true,
+                ..GameConfig::default()
+            },
+        );
+        assert_eq!(spare.play(spare.secret_number), GuessResult::Correct);
+        assert_eq!(spare.state(), GameState::Won);
+    }
+
+    #[test]
+    fn test_max_score() {
+        let config = GameConfig {
+            min_num: 1,
+            max_num: 2,
+            lives: 1000,
+            max_score: Some(5000),
+            ..GameConfig::default()
+        };
+        let mut huge = Game::from_seed(0, config);
+        huge.play(huge.secret_number);
+        assert_eq!(huge.score(), 5000);
+        assert_eq!(
+            huge.outcome(),
+            Some(GameOutcome::Won {
+                attempts: 1,
+                score: 5000
+            })
+        );
+
+        let mut modest = Game::from_seed(
+            0,
+            GameConfig {
+                lives: 10,
+                ..config
+            },
+        );
+        modest.play(modest.secret_number);
+        assert_eq!(modest.score(), 1100);
+
+        let mut uncapped = Game::from_seed(
+            0,
+            GameConfig {
+                max_score: None,
+                ..config
+            },
+        );
+        uncapped.play(uncapped.secret_number);
+        assert_eq!(uncapped.score(), 100_100);
+    }
+
+    #[test]
+    fn test_guess_value() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 80,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        for bounds in [(1, 100), (51, 100), (76, 100)] {
+            assert_eq!(game.bounds(), bounds);
+            let best = |value: fn(&GuessValue) -> f64| {
+                (0..=101)
+                    .map(|guess| value(&game.guess_value(guess)))
+                    .fold(f64::MIN, f64::max)
+            };
+            let middle = game.guess_value(game.suggest_guess());
+            assert_eq!(
+                middle.expected_reduction,
+                best(|value| value.expected_reduction)
+            );
+            assert_eq!(middle.information, best(|value| value.information));
+            game.play(game.suggest_guess());
+        }
+        assert_eq!(game.guess_value(25), game.guess_value(101));
+        assert_eq!(game.guess_value(25).expected_reduction, 0.0);
+        assert_eq!(game.guess_value(76).chance_correct, 1.0 / 12.0);
+        assert_eq!(game.guess_value(76).expected_reduction, 1.0);
+
+        let excluded = Game {
+            excluded: vec![3],
+            ..Game::new(Some(1), Some(4), Some(10), &mut rng)
+        };
+        assert_eq!(excluded.guess_value(3).information, 0.0);
+        assert_eq!(excluded.guess_value(2).expected_reduction, 2.0);
+        assert_eq!(excluded.guess_value(4).expected_reduction, 1.0);
+    }
+
+    #[test]
+    fn test_optimal_information_gain() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let gain = |max: u32| {
+            Game::new(Some(1), Some(max), Some(10), &mut rng.clone()).optimal_information_gain()
+        };
+
+        assert_eq!(gain(1), 0.0);
+        assert_eq!(gain(2), 1.0);
+        assert_eq!(gain(3), 3f64.log2());
+        for max in [100, 101, 1000, 1001] {
+            let bits = gain(max);
+            assert!(bits > 1.0 && bits < 1.1, "{max}: {bits}");
+        }
+        assert!(gain(1001) < gain(101));
+        assert!((gain(1000) - gain(1001)).abs() < 0.01);
+
+        let mut game = Game {
+            secret_number: 80,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        game.play(50);
+        assert_eq!(
+            game.optimal_information_gain(),
+            game.guess_value(game.suggest_guess()).information
+        );
+    }
+
+    #[test]
+    fn test_timed_score() {
+        let play = |gap: Duration| {
+            let mut rng = StdRng::from_seed(Default::default());
+            let mut game = Game {
+                secret_number: 50,
+                ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+            };
+            let start = Instant::now();
+            for (i, guess) in [25, 75, 50].into_iter().enumerate() {
+                game.play_at(guess, start + gap * i as u32);
+            }
+            game
+        };
+
+        let quick = play(Duration::from_secs(2));
+        let slow = play(Duration::from_secs(20));
+        assert_eq!(quick.score(), slow.score());
+        assert!(quick.timed_score() > slow.timed_score());
+        assert!(quick.timed_score() < Some(quick.score()));
+        assert_eq!(slow.timed_score(), Some(119));
+
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut untimed = Game {
+            secret_number: 50,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        untimed.play(50);
+        assert_eq!(untimed.timed_score(), None);
+
+        let mut lost = Game::new(Some(1), Some(100), Some(1), &mut rng);
+        lost.play_at(101, Instant::now());
+        assert_eq!(lost.timed_score(), None);
+    }
+
+    #[test]
+    fn test_is_linear_scanning() {
+        let play = |secret, guesses: &[u32]| {
+            let mut rng = StdRng::from_seed(Default::default());
+            let mut game = Game {
+                secret_number: secret,
+                ..Game::new(Some(1), Some(100), Some(20), &mut rng)
+            };
+            for &guess in guesses {
+                game.play(guess);
+            }
+            game.is_linear_scanning()
+        };
+
+        assert!(play(90, &[1, 2, 3, 4]));
+        assert!(play(5, &[100, 90, 75, 65, 55]));
+        assert!(!play(90, &[1, 2, 3]));
+        assert!(!play(90, &[50, 75, 88, 94]));
+        assert!(!play(60, &[50, 75, 62, 56]));
+        assert!(!play(90, &[1, 2, 3, 20]));
+        assert!(!play(90, &[5, 5, 5, 5]));
+    }
+
+    #[test]
+    fn test_bias_toward() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 100,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        assert_eq!(game.bias_toward(), Bias::Balanced);
+        for guess in [5, 12, 20, 31, 40] {
+            game.play(guess);
+        }
+        assert_eq!(game.bias_toward(), Bias::Low);
+
+        let mut game = Game {
+            secret_number: 50,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        for guess in [10, 91, 25, 76, 40, 61] {
+            game.play(guess);
+        }
+        assert_eq!(game.bias_toward(), Bias::Balanced);
+
+        let mut game = Game {
+            secret_number: 1,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        for guess in [95, 80, 70] {
+            game.play(guess);
+        }
+        assert_eq!(game.bias_toward(), Bias::High);
+    }
+
+    #[test]
+    fn test_free_first_guess() {
+        let config = GameConfig {
+            lives: 3,
+            free_first_guess: true,
+            ..GameConfig::default()
+        };
+        let mut game = Game {
+            secret_number: 12,
+            ..Game::from_seed(0, config)
+        };
+        assert_eq!(game.play(4), GuessResult::TooLow);
+        assert_eq!(game.lives(), 3);
+        assert_eq!(game.attempts, 1);
+        assert_eq!(game.play(18), GuessResult::TooHigh);
+        assert_eq!(game.lives(), 2);
+
+        let mut game = Game {
+            secret_number: 12,
+            ..Game::from_seed(
+                0,
+                GameConfig {
+                    free_first_guess: false,
+                    ..config
+                },
+            )
+        };
+        game.play(4);
+        assert_eq!(game.lives(), 2);
+    }
+
+    /// Collects every end state reachable by guessing inside `low..=high`: `Some(k)` for a win on
+    /// the `k`th guess from now and `None` for a loss.
+    fn explore_end_states(
+        low: u32,
+        high: u32,
+        lives: u32,
+        k: usize,
+        states: &mut std::collections::HashSet<Option<usize>>,
+    ) {
+        for guess in low..=high {
+            states.insert(Some(k));
+            let below = (guess > low).then(|| (low, guess - 1));
+            let above = (guess < high).then(|| (guess + 1, high));
+            for (low, high) in below.into_iter().chain(above) {
+                if lives == 1 {
+                    states.insert(None);
+                } else {
+                    explore_end_states(low, high, lives - 1, k + 1, states);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_reachable_states() {
+        for max in 1..=6 {
+            for lives in 1..=7 {
+                let mut rng = StdRng::from_seed(Default::default());
+                let game = Game::new(Some(1), Some(max), Some(lives), &mut rng);
+                let mut states = std::collections::HashSet::new();
+                explore_end_states(1, max, lives, 1, &mut states);
+                assert_eq!(
+                    game.reachable_states(),
+                    states.len(),
+                    "1..={max}, {lives} lives"
+                );
+            }
+        }
+
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 2,
+            ..Game::new(Some(1), Some(4), Some(2), &mut rng)
+        };
+        assert_eq!(game.reachable_states(), 3);
+        game.play(3);
+        assert_eq!(game.reachable_states(), 2);
+        game.play(1);
+        assert_eq!(game.reachable_states(), 1);
+        assert!(game.is_over());
+
+        let big = Game::new(Some(0), Some(u32::MAX), Some(40), &mut rng);
+        assert_eq!(big.reachable_states(), 41);
+    }
+
+    #[test]
+    fn test_last_explanation() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 12,
+            tutorial: true,
+            ..Game::new(Some(1), Some(20), Some(5), &mut rng)
+        };
+        assert_eq!(game.last_explanation(), None);
+
+        game.play(15);
+        assert_eq!(
+            game.last_explanation().as_deref(),
+            Some("Since 15 was too high, the number is between 1 and 14.")
+        );
+        game.play(8);
+        assert_eq!(
+            game.last_explanation().as_deref(),
+            Some("Since 8 was too low, the number is between 9 and 14.")
+        );
+        game.with_head_start(11, 13).unwrap();
+        game.play(11);
+        game.play(13);
+        assert_eq!(
+            game.last_explanation().as_deref(),
+            Some("Since 13 was too high, the number must be 12.")
+        );
+        game.play(12);
+        assert_eq!(
+            game.last_explanation().as_deref(),
+            Some("12 was the number. Well done!")
+        );
+
+        game.tutorial = false;
+        assert_eq!(game.last_explanation(), None);
+    }
+
+    #[test]
+    fn test_guesses_remaining_optimal() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 11,
+            ..Game::new(Some(1), Some(16), Some(10), &mut rng)
+        };
+        assert_eq!(game.guesses_remaining_optimal(), 5);
+        game.play(8);
+        assert_eq!(game.guesses_remaining_optimal(), 4);
+        game.play(12);
+        assert_eq!(game.guesses_remaining_optimal(), 2);
+        game.play(30);
+        assert_eq!(game.guesses_remaining_optimal(), 2);
+        game.play(10);
+        assert_eq!(game.bounds(), (11, 11));
+        assert_eq!(game.guesses_remaining_optimal(), 1);
+        game.play(11);
+        assert_eq!(game.guesses_remaining_optimal(), 0);
+    }
+
+    #[test]
+    fn test_percentile_rank() {
+        let won = |score| GameOutcome::Won { attempts: 5, score };
+        let population: Vec<_> = (1..=20).map(|i| won(i * 10)).collect();
+        let top_quartile = won(185);
+        assert_eq!(top_quartile.percentile_rank(&population), 0.9);
+        assert!(top_quartile.percentile_rank(&population) >= 0.75);
+
+        assert_eq!(won(100).percentile_rank(&population), 0.45);
+        assert_eq!(won(1000).percentile_rank(&population), 1.0);
+        let lost = GameOutcome::Lost {
+            attempts: 9,
+            secret: 4,
+        };
+        assert_eq!(lost.percentile_rank(&population), 0.0);
+        assert_eq!(won(10).percentile_rank(&[lost, lost]), 1.0);
+        assert_eq!(top_quartile.percentile_rank(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_compare_to() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let reference = GameOutcome::Won {
+            attempts: 3,
+            score: 200,
+        };
+
+        let mut faster = Game {
+            secret_number: 7,
+            ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+        };
+        faster.play(5);
+        faster.play(7);
+        let comparison = faster.compare_to(&reference).unwrap();
+        assert_eq!(comparison.attempts, Ordering::Less);
+        assert_eq!(comparison.score, Ordering::Greater);
+        assert!(comparison.beat());
+
+        let mut slower = Game {
+            secret_number: 7,
+            ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+        };
+        for guess in [1, 2, 3, 4, 7] {
+            slower.play(guess);
+        }
+        let comparison = slower.compare_to(&reference).unwrap();
+        assert_eq!(comparison.attempts, Ordering::Greater);
+        assert_eq!(comparison.score, Ordering::Less);
+        assert!(!comparison.beat());
+
+        let mut lost = Game {
+            secret_number: 7,
+            ..Game::new(Some(1), Some(10), Some(1), &mut rng)
+        };
+        lost.play(1);
+        assert!(!lost.compare_to(&reference).unwrap().beat());
+        let tie = GameOutcome::Lost {
+            attempts: 1,
+            secret: 7,
+        };
+        assert!(!lost.compare_to(&tie).unwrap().beat());
+    }
+
+    #[test]
+    fn test_is_wasteful() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 14,
+            ..Game::new(Some(1), Some(20), Some(5), &mut rng)
+        };
+        assert!(!game.is_wasteful(1));
+        assert!(!game.is_wasteful(20));
+        assert!(game.is_wasteful(21));
+
+        game.play(10);
+        game.play(16);
+        assert_eq!(game.bounds(), (11, 15));
+        assert!(game.is_wasteful(10));
+        assert!(game.is_wasteful(4));
+        assert!(game.is_wasteful(16));
+        assert!(!game.is_wasteful(11));
+        assert!(!game.is_wasteful(13));
+        assert!(!game.is_wasteful(15));
+    }
+
+    #[test]
+    fn test_bisection_accuracy() {
+        let play = |guesses: &[u32]| {
+            let mut rng = StdRng::from_seed(Default::default());
+            let mut game = Game {
+                secret_number: 70,
+                ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+            };
+            for &guess in guesses {
+                game.play(guess);
+            }
+            game.bisection_accuracy().unwrap()
+        };
+
+        assert_eq!(play(&[50, 75, 62, 68, 71, 69, 70]), 1.0);
+        assert_eq!(play(&[51, 76, 63, 69, 72, 70]), 1.0);
+        let nearly = play(&[48, 75, 62, 68, 71, 69, 70]);
+        assert!(nearly > 0.99 && nearly < 1.0, "{nearly}");
+        let off_centre = play(&[20, 90, 40, 80, 70]);
+        assert!(off_centre < 0.5, "{off_centre}");
+        assert_eq!(play(&[1, 100, 2]), 0.0);
+        assert_eq!(play(&[101]), 0.0);
+    }
+
+    #[test]
+    fn test_exploration_coverage() {
+        let play = |max: u32, guesses: &[u32]| {
+            let mut rng = StdRng::from_seed(Default::default());
+            let mut game = Game {
+                secret_number: 0,
+                ..Game::new(Some(1), Some(max), Some(20), &mut rng)
+            };
+            for &guess in guesses {
+                game.play(guess);
+            }
+            game.exploration_coverage()
+        };
+
+        assert_eq!(play(100, &[]), 0.0);
+        assert_eq!(play(100, &[41, 43, 45, 47, 49, 50]), 0.1);
+        assert_eq!(play(100, &[5, 15, 25, 35, 45, 55, 65, 75, 85, 95]), 1.0);
+        assert_eq!(play(100, &[10, 11, 90, 91]), 0.4);
+        assert_eq!(play(100, &[101, 1000]), 0.0);
+        assert_eq!(play(4, &[1, 2]), 0.5);
+        assert_eq!(play(u32::MAX, &[1, u32::MAX]), 0.2);
+    }
+
+    #[test]
+    fn test_was_closest_yet() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 70,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        assert!(!game.was_closest_yet());
+        let closest: Vec<bool> = [50, 90, 75, 60, 80, 74, 66]
+            .into_iter()
+            .map(|guess| {
+                game.play(guess);
+                game.was_closest_yet()
+            })
+            .collect();
+        assert_eq!(closest, [false, false, true, false, false, true, false]);
+        game.play(70);
+        assert!(game.was_closest_yet());
+    }
+
+    #[test]
+    fn test_minimax_guess() {
+        let mut rng = StdRng::from_seed(Default::default());
+        for (min, max) in [(1, 1), (1, 2), (1, 10), (1, 100), (0, 1001), (5, u32::MAX)] {
+            let game = Game::new(Some(min), Some(max), Some(10), &mut rng);
+            assert_eq!(
+                game.minimax_guess(),
+                Some(game.suggest_guess()),
+                "{min}..={max}"
+            );
+        }
+
+        let mut game = Game {
+            secret_number: 70,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        while !game.is_over() {
+            let guess = game.minimax_guess().unwrap();
+            assert_eq!(guess, game.suggest_guess());
+            game.play(guess);
+        }
+        assert_eq!(game.minimax_guess(), None);
+
+        let worst_case = |game: &Game, guess: u32| {
+            let below = if guess > game.low {
+                game.count_between(game.low, guess - 1)
+            } else {
+                0
+            };
+            below.max(game.possible_count() - below - 1)
+        };
+        let excluded = Game {
+            excluded: vec![1, 2, 3, 4, 5, 6],
+            ..Game::new(Some(1), Some(20), Some(10), &mut rng)
+        };
+        let guess = excluded.minimax_guess().unwrap();
+        assert_eq!(guess, 13);
+        let best = (7..=20)
+            .map(|candidate| worst_case(&excluded, candidate))
+            .min()
+            .unwrap();
+        assert_eq!(worst_case(&excluded, guess), best);
+        assert!(worst_case(&excluded, excluded.suggest_guess()) > best);
+    }
+
+    #[test]
+    fn test_over_par() {
+        let play = |guesses: &[u32]| {
+            let mut rng = StdRng::from_seed(Default::default());
+            let mut game = Game {
+                secret_number: 70,
+                ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+            };
+            for &guess in guesses {
+                game.play(guess);
+            }
+            game.over_par()
+        };
+
+        assert_eq!(play(&[50, 75, 62, 68, 71, 69, 70]), Some(0));
+        assert_eq!(play(&[70]), Some(0));
+        assert_eq!(play(&[10, 20, 30, 40, 50, 60, 65, 68, 70]), Some(2));
+        assert_eq!(play(&[50, 75]), None);
+        assert_eq!(play(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]), None);
+    }
+
+    #[test]
+    fn test_comeback_factor() {
+        let play = |lives, guesses: &[u32]| {
+            let mut rng = StdRng::from_seed(Default::default());
+            let mut game = Game {
+                secret_number: 70,
+                ..Game::new(Some(1), Some(100), Some(lives), &mut rng)
+            };
+            for &guess in guesses {
+                game.play(guess);
+            }
+            game.comeback_factor()
+        };
+
+        let easy = play(7, &[70]).unwrap();
+        assert_eq!(easy, 0.0);
+        let dramatic = play(3, &[10, 20, 70]).unwrap();
+        assert!((dramatic - (1.0 - 1.0 / 80.0)).abs() < 1e-9);
+        assert!(dramatic > play(4, &[10, 20, 70]).unwrap());
+        assert_eq!(play(7, &[50, 75]), None);
+        assert_eq!(play(2, &[10, 20, 70]), None);
+    }
+
+    #[test]
+    fn test_wasted_guesses() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 14,
+            ..Game::new(Some(1), Some(20), Some(10), &mut rng)
+        };
+        assert_eq!(game.wasted_guesses(), 0);
+        for guess in [10, 16, 10, 3, 12, 21, 14] {
+            game.play(guess);
+        }
+        assert_eq!(game.history.len(), 7);
+        assert_eq!(game.wasted_guesses(), 3);
+
+        let mut careful = Game {
+            secret_number: 14,
+            ..Game::new(Some(1), Some(20), Some(10), &mut rng)
+        };
+        for guess in [10, 15, 13, 14] {
+            careful.play(guess);
+        }
+        assert_eq!(careful.wasted_guesses(), 0);
+    }
+
+    #[test]
+    fn test_free_hints() {
+        let config = GameConfig {
+            min_num: 1,
+            max_num: 100,
+            lives: 3,
+            free_hints: true,
+            ..GameConfig::default()
+        };
+        let mut game = Game {
+            secret_number: 70,
+            ..Game::from_seed(0, config)
+        };
+        assert_eq!(game.hint_half(), Some(false));
+        assert_eq!(game.bounds(), (51, 100));
+        assert_eq!(game.hint_half(), Some(true));
+        assert_eq!(game.bounds(), (51, 75));
+        assert_eq!(game.lives(), 3);
+
+        let mut game = Game {
+            secret_number: 70,
+            ..Game::from_seed(
+                0,
+                GameConfig {
+                    free_hints: false,
+                    ..config
+                },
+            )
+        };
+        game.hint_half();
+        assert_eq!(game.bounds(), (51, 100));
+        assert_eq!(game.lives(), 2);
+    }
+
+    #[test]
+    fn test_hint_budget() {
+        let config = GameConfig {
+            min_num: 1,
+            max_num: 100,
+            lives: 5,
+            hint_budget: Some(3),
+            ..GameConfig::default()
+        };
+        let mut game = Game {
+            secret_number: 70,
+            ..Game::from_seed(0, config)
+        };
+        assert_eq!(game.hints_remaining(), 3);
+        assert_eq!(game.hint_half(), Some(false));
+        assert_eq!(game.hint_bit(1), Some(true));
+        assert_eq!(game.hint_bit(7), None);
+        assert_eq!(game.hints_remaining(), 1);
+        assert_eq!(game.hint_half(), Some(true));
+        assert_eq!(game.bounds(), (51, 75));
+        assert_eq!(game.hints_remaining(), 0);
+        assert_eq!(game.lives(), 5);
+
+        assert_eq!(game.hint_half(), None);
+        assert_eq!(game.hint_bit(0), None);
+        assert_eq!(game.bounds(), (51, 75));
+        assert_eq!(game.lives(), 5);
+        assert_eq!(game.play(60), GuessResult::TooLow);
+        assert_eq!(game.lives(), 4);
+
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut unbudgeted = Game::new(Some(1), Some(100), Some(2), &mut rng);
+        assert_eq!(unbudgeted.hints_remaining(), 2);
+        unbudgeted.hint_half();
+        assert_eq!(unbudgeted.hints_remaining(), 1);
+        unbudgeted.free_hints = true;
+        assert_eq!(unbudgeted.hints_remaining(), u32::MAX);
+        unbudgeted.give_up();
+        assert_eq!(unbudgeted.hints_remaining(), 0);
+    }
+
+    #[test]
+    fn test_secret_rank_among_guesses() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 42,
+            ..Game::new(Some(1), Some(100), Some(4), &mut rng)
+        };
+        for guess in [50, 25, 41, 43] {
+            game.play(guess);
+        }
+        assert!(game.is_over());
+        assert_eq!(game.secret_rank_among_guesses(), Some(2));
+
+        let mut game = Game {
+            secret_number: 42,
+            ..Game::new(Some(1), Some(100), Some(7), &mut rng)
+        };
+        for guess in [10, 20, 30] {
+            game.play(guess);
+        }
+        assert_eq!(game.secret_rank_among_guesses(), None);
+        game.play(42);
+        assert_eq!(game.secret_rank_among_guesses(), Some(3));
+    }
+
+    #[test]
+    fn test_tournament() {
+        let config = GameConfig {
+            max_num: 1000,
+            ..GameConfig::default()
+        };
+        let secrets = |seed, count| -> Vec<u32> {
+            Game::tournament(seed, count, config)
+                .iter()
+                .map(|game| game.secret_number)
+                .collect()
+        };
+        assert_eq!(secrets(3, 10), secrets(3, 10));
+        assert_ne!(secrets(3, 10), secrets(4, 10));
+        assert_eq!(secrets(3, 4), secrets(3, 10)[..4]);
+        assert!(secrets(3, 0).is_empty());
+
+        let games = Game::tournament(3, 10, config);
+        for game in &games {
+            let replayed = Game::from_seed(game.seed.unwrap(), config);
+            assert_eq!(replayed.secret_number, game.secret_number);
+        }
+    }
+
+    #[test]
+    fn test_narrowing_sparkline() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 80,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        assert_eq!(game.narrowing_sparkline(), "█");
+        for guess in [50, 75, 88, 81, 78, 79, 80] {
+            game.play(guess);
+        }
+        let sparkline: Vec<char> = game.narrowing_sparkline().chars().collect();
+        assert_eq!(sparkline.len(), 8);
+        assert_eq!(sparkline[0], '█');
+        assert_eq!(sparkline[1], '▄');
+        assert_eq!(sparkline[7], '▁');
+        assert!(sparkline.windows(2).all(|pair| pair[0] >= pair[1]));
+
+        let mut slow = Game {
+            secret_number: 80,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        for guess in [1, 2, 3] {
+            slow.play(guess);
+        }
+        assert_eq!(slow.narrowing_sparkline(), "████");
+    }
+
+    #[test]
+    fn test_announced_range() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 50,
+            ..Game::new(Some(20), Some(80), Some(7), &mut rng)
+        };
+        assert_eq!(game.announced_range(), (20, 80));
+        assert_eq!(
+            game.with_announced_range(90, 10),
+            Err(GameError::InvalidRange)
+        );
+        assert_eq!(
+            game.with_announced_range(21, 100),
+            Err(GameError::AnnouncedRangeTooNarrow)
+        );
+        assert_eq!(
+            game.with_announced_range(1, 79),
+            Err(GameError::AnnouncedRangeTooNarrow)
+        );
+        assert_eq!(game.bounds(), (20, 80));
+
+        assert_eq!(game.with_announced_range(20, 80), Ok(()));
+        assert_eq!(game.with_announced_range(1, 100), Ok(()));
+        assert_eq!(game.announced_range(), (1, 100));
+        assert_eq!(game.bounds(), (1, 100));
+        assert_eq!(game.as_tracker().range(), (1, 100));
+        assert_eq!(game.hint_half(), Some(true));
+        assert_eq!(game.bounds(), (1, 50));
+
+        game.bonus_mode = true;
+        game.play(50);
+        assert_eq!(game.bounds(), (1, 100));
+        assert!((20..=80).contains(&game.secret_number));
+    }
+
+    #[test]
+    fn test_normalized_score() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut easy = Game {
+            secret_number: 17,
+            ..Game::new(Some(1), Some(20), Some(10), &mut rng)
+        };
+        let mut hard = Game {
+            secret_number: 1000,
+            ..Game::new(Some(1), Some(1000), Some(12), &mut rng)
+        };
+        assert_eq!(easy.normalized_score(), 0.0);
+        for game in [&mut easy, &mut hard] {
+            while !game.is_over() {
+                game.play(game.suggest_guess());
+            }
+            assert!(game.won);
+        }
+
+        assert!(easy.score() > 2 * hard.score());
+        let (easy, hard) = (easy.normalized_score(), hard.normalized_score());
+        assert!((easy - hard).abs() < 0.25, "easy {easy}, hard {hard}");
+    }
+
+    #[test]
+    fn test_explain() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 30,
+            ..Game::new(Some(1), Some(100), Some(7), &mut rng)
+        };
+        let result = game.play(60);
+        assert_eq!(
+            game.explain(60, &result),
+            "60 was too high, so the answer is between 1 and 59 — try the middle, around 30."
+        );
+        let result = game.play(29);
+        assert_eq!(
+            game.explain(29, &result),
+            "29 was too low, so the answer is between 30 and 59 — try the middle, around 44."
+        );
+        let result = game.play(31);
+        assert_eq!(
+            game.explain(31, &result),
+            "31 was too high, so the answer must be 30 — guess it!"
+        );
+        let result = game.play(30);
+        assert_eq!(
+            game.explain(30, &result),
+            "30 was right — the answer is 30."
+        );
+    }
+
+    #[test]
+    fn test_rng_kind() {
+        for kind in [RngKind::Std, RngKind::SmallFast, RngKind::ChaCha] {
+            let config = GameConfig {
+                max_num: 1_000_000,
+                rng_kind: kind,
+                ..GameConfig::default()
+            };
+            let secrets = |seed| -> Vec<u32> {
+                Game::tournament(seed, 10, config)
+                    .iter()
+                    .map(|game| game.secret_number)
+                    .collect()
+            };
+            assert_eq!(secrets(5), secrets(5), "{kind:?}");
+            assert_ne!(secrets(5), secrets(6), "{kind:?}");
+
+            let mut game = Game {
+                bonus_mode: true,
+                ..Game::from_seed(5, config)
+            };
+            let mut again = Game {
+                bonus_mode: true,
+                ..Game::from_seed(5, config)
+            };
+            assert_eq!(game.secret_rng.is_some(), kind != RngKind::Std);
+            game.play(game.secret_number);
+            again.play(again.secret_number);
+            assert_eq!(game.secret_number, again.secret_number, "{kind:?}");
+        }
+
+        let secret = |kind| {
+            let config = GameConfig {
+                max_num: 1_000_000,
+                rng_kind: kind,
+                ..GameConfig::default()
+            };
+            Game::from_seed(5, config).secret_number
+        };
+        assert_ne!(secret(RngKind::Std), secret(RngKind::SmallFast));
+        assert_ne!(secret(RngKind::Std), secret(RngKind::ChaCha));
+    }
+
+    #[test]
+    fn test_apply_guess_str() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 12,
+            ..Game::new(Some(1), Some(20), Some(5), &mut rng)
+        };
+        assert_eq!(game.apply_guess_str("8"), Ok(GuessResult::TooLow));
+        assert_eq!(game.lives(), 4);
+
+        let errors = [
+            ("", GuessInputError::Empty),
+            (" \t", GuessInputError::Empty),
+            ("abc", GuessInputError::NotANumber),
+            ("-3", GuessInputError::NotANumber),
+            ("1.5", GuessInputError::NotANumber),
+            ("0", GuessInputError::OutOfRange { min: 1, max: 20 }),
+            ("21", GuessInputError::OutOfRange { min: 1, max: 20 }),
+            (
+                "99999999999",
+                GuessInputError::OutOfRange { min: 1, max: 20 },
+            ),
+        ];
+        for (input, error) in errors {
+            assert_eq!(game.apply_guess_str(input), Err(error), "{input:?}");
+        }
+        assert_eq!(game.lives(), 4);
+        assert_eq!(game.attempts, 1);
+
+        assert_eq!(game.apply_guess_str(" 12\n"), Ok(GuessResult::Correct));
+    }
+
+    #[test]
+    fn test_out_of_range_penalty() {
+        let play = |penalty| {
+            let config = GameConfig {
+                min_num: 1,
+                max_num: 20,
+                lives: 2,
+                out_of_range_penalty: penalty,
+                ..GameConfig::default()
+            };
+            let mut game = Game::from_seed(0, config);
+            let error = GuessInputError::OutOfRange { min: 1, max: 20 };
+            assert_eq!(game.apply_guess_str("21"), Err(error));
+            assert_eq!(game.attempts, 0);
+            game
+        };
+
+        assert_eq!(play(0).lives(), 2);
+
+        let mut penalized = play(1);
+        assert_eq!(penalized.lives(), 1);
+        assert_eq!(
+            penalized.apply_guess_str("abc"),
+            Err(GuessInputError::NotANumber)
+        );
+        assert_eq!(penalized.lives(), 1);
+        let _ = penalized.apply_guess_str("0");
+        assert_eq!(penalized.state(), GameState::Lost);
+        let _ = penalized.apply_guess_str("0");
+        assert_eq!(penalized.lives(), 0);
+
+        assert_eq!(play(5).state(), GameState::Lost);
+    }
+
+    #[test]
+    fn test_expected_random_score() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let generous = Game::new(Some(1), Some(10), Some(10), &mut rng);
+        let tight = Game::new(Some(1), Some(100), Some(5), &mut rng);
+        assert!(generous.expected_random_score() > 10.0 * tight.expected_random_score());
+
+        // Each of the 5 guesses wins a tenth of the time, scoring 600, 250, 133, 75 and 40.
+        let small = Game::new(Some(1), Some(10), Some(5), &mut rng);
+        assert!((small.expected_random_score() - 109.8).abs() < 1e-9);
+
+        let single = Game::new(Some(4), Some(4), Some(3), &mut rng);
+        assert_eq!(single.expected_random_score(), 400.0);
+    }
+
+    #[test]
+    fn test_lives_until_unwinnable() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 60,
+            ..Game::new(Some(1), Some(100), Some(8), &mut rng)
+        };
+        assert_eq!(game.lives_until_unwinnable(), 2);
+        assert!(game.lives_until_unwinnable() < game.lives());
+
+        game.play(1);
+        assert_eq!(game.lives_until_unwinnable(), 1);
+        assert!(game.is_winnable());
+        game.play(2);
+        assert_eq!(game.lives_until_unwinnable(), 0);
+        assert!(!game.is_winnable());
+
+        let mut game = Game {
+            secret_number: 60,
+            ..Game::new(Some(1), Some(100), Some(8), &mut rng)
+        };
+        game.with_head_start(59, 61).unwrap();
+        game.play(59);
+        game.play(61);
+        assert_eq!(game.bounds(), (60, 60));
+        assert_eq!(game.lives(), 6);
+        assert_eq!(game.lives_until_unwinnable(), game.lives());
+
+        game.play(60);
+        assert_eq!(game.lives_until_unwinnable(), 0);
+    }
+
+    #[test]
+    fn test_quit() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 9,
+            ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+        };
+        game.play(3);
+        assert_eq!(game.quit(false), "Goodbye!");
+        assert_eq!(
+            game.outcome(),
+            Some(GameOutcome::Lost {
+                attempts: 1,
+                secret: 9
+            })
+        );
+
+        let mut game = Game {
+            secret_number: 9,
+            ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+        };
+        assert_eq!(game.quit(true), "Goodbye! The secret number was 9.");
+        assert_eq!(game.state(), GameState::Lost);
+        assert_ne!(QUIT_EXIT_CODE, 0);
+    }
+
+    #[test]
+    fn test_lives_buffer() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 90,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        assert_eq!(game.lives_buffer(), 3);
+        for guess in 1..=5 {
+            game.play(guess);
+        }
+        assert_eq!(game.possible_count(), 95);
+        assert_eq!(game.lives_buffer(), -2);
+
+        game.play(89);
+        assert_eq!(game.bounds(), (90, 100));
+        assert_eq!(game.lives_buffer(), 0);
+        game.with_head_start(90, 90).unwrap();
+        assert_eq!(game.lives_buffer(), 4);
+    }
+
+    #[test]
+    fn test_history_cap() {
+        let config = GameConfig {
+            min_num: 1,
+            max_num: 100,
+            history_cap: Some(3),
+            ..GameConfig::default()
+        };
+        let mut game = Game {
+            secret_number: 37,
+            ..Game::from_seed(0, config)
+        };
+        let start = Instant::now();
+        for (i, guess) in [50, 25, 30, 40, 35].into_iter().enumerate() {
+            game.play_at(guess, start + Duration::from_secs(i as u64));
+        }
+        let kept: Vec<u32> = game.history.iter().map(|record| record.guess).collect();
+        assert_eq!(kept, [30, 40, 35]);
+        assert_eq!(game.guess_timestamps().len(), 3);
+        assert_eq!(game.attempts, 5);
+        assert_eq!(game.bounds(), (36, 39));
+        assert_eq!(game.history.last().unwrap().low, 36);
+
+        assert_eq!(game.play(37), GuessResult::Correct);
+        assert_eq!(game.history.len(), 3);
+        assert_eq!(game.outcome().map(|outcome| outcome.attempts()), Some(6));
+    }
+
+    #[test]
+    fn test_current_find_streak() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            bonus_mode: true,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        assert_eq!(game.current_find_streak(), 0);
+        for streak in 1..=4 {
+            game.play(game.secret_number);
+            assert_eq!(game.current_find_streak(), streak);
+        }
+
+        let wrong = if game.secret_number == 1 { 2 } else { 1 };
+        game.play(wrong);
+        assert_eq!(game.current_find_streak(), 0);
+        game.play(game.secret_number);
+        assert_eq!(game.current_find_streak(), 1);
+
+        let wrong = if game.secret_number == 100 { 99 } else { 100 };
+        game.play(wrong);
+        game.play(game.secret_number);
+        game.play(game.secret_number);
+        assert_eq!(game.current_find_streak(), 2);
+        assert_eq!(game.bonus_count, 6);
+    }
+
+    #[test]
+    fn test_lie_chance() {
+        let config = GameConfig {
+            min_num: 1,
+            max_num: 100,
+            lives: 100,
+            lie_chance: 0.8,
+            ..GameConfig::default()
+        };
+        let mut game = Game {
+            secret_number: 50,
+            ..Game::from_seed(7, config)
+        };
+        let results: Vec<GuessResult> = (1..=100)
+            .filter(|&guess| guess != 50)
+            .map(|guess| game.play(guess))
+            .collect();
+        let lies = results
+            .iter()
+            .zip((1..=100).filter(|&guess| guess != 50))
+            .filter(|&(&result, guess)| result != compare(guess, 50))
+            .count();
+        assert!(lies > 0);
+        assert!(lies < results.len());
+        assert!(!results.contains(&GuessResult::Correct));
+        assert_eq!(game.play(50), GuessResult::Correct);
+
+        let mut honest = Game {
+            secret_number: 50,
+            ..Game::from_seed(
+                7,
+                GameConfig {
+                    lie_chance: 0.0,
+                    ..config
+                },
+            )
+        };
+        assert!((1..50).all(|guess| honest.play(guess) == GuessResult::TooLow));
+    }
+
+    #[test]
+    fn test_lie_rate() {
+        let config = GameConfig {
+            min_num: 1,
+            max_num: 100,
+            lives: 100_000,
+            lie_chance: 0.3,
+            ..GameConfig::default()
+        };
+        let mut game = Game {
+            secret_number: 50,
+            ..Game::from_seed(11, config)
+        };
+        let trials = 20_000;
+        let lies = (0..trials)
+            .map(|i| if i % 2 == 0 { 1 } else { 100 })
+            .filter(|&guess| game.play(guess) != compare(guess, 50))
+            .count();
+        let rate = lies as f64 / f64::from(trials);
+        assert!((rate - 0.3).abs() < 0.02, "lie rate {rate}");
+        assert_eq!(game.play(50), GuessResult::Correct);
+    }
+
+    #[test]
+    fn test_lies_with_reversed_strategy() {
+        let reversed = |lie_chance, seed| {
+            let config = GameConfig {
+                min_num: 1,
+                max_num: 100,
+                lives: 100,
+                lie_chance,
+                ..GameConfig::default()
+            };
+            Game {
+                secret_number: 37,
+                strategy: Box::new(Reversed),
+                ..Game::from_seed(seed, config)
+            }
+        };
+
+        let mut honest = reversed(1e-12, 1);
+        let mut guesses = 0;
+        loop {
+            let guess = honest.suggest_guess();
+            guesses += 1;
+            if honest.play(guess) == GuessResult::Correct {
+                break;
+            }
+            assert!((honest.low..=honest.high).contains(&37));
+        }
+        assert!(guesses <= 7);
+
+        let mut always = reversed(1.0, 1);
+        assert_eq!(always.play(50), GuessResult::TooHigh);
+        assert_eq!(always.bounds(), (51, 100));
+
+        let mut sometimes = reversed(0.3, 2);
+        let mut lied = false;
+        for guess in (1..=100).filter(|&guess| guess != 37).take(60) {
+            lied |= sometimes.play(guess) != Reversed.compare(guess, 37);
+            if !lied {
+                assert!((sometimes.low..=sometimes.high).contains(&37));
+            }
+        }
+        assert!(lied);
+    }
+
+    #[test]
+    fn test_bounds_follow_lies() {
+        let config = GameConfig {
+            min_num: 1,
+            max_num: 100,
+            lives: 10,
+            lie_chance: 1.0,
+            tutorial: true,
+            ..GameConfig::default()
+        };
+        let mut liar = Game {
+            secret_number: 70,
+            ..Game::from_seed(1, config)
+        };
+        assert_eq!(liar.play(50), GuessResult::TooHigh);
+        assert_eq!(liar.bounds(), (1, 49));
+        assert_eq!(
+            liar.last_explanation().unwrap(),
+            "Since 50 was too high, the number is between 1 and 49."
+        );
+        assert_eq!(liar.public_state().high, 49);
+        assert!(liar.suggest_guess() < 50);
+        assert_eq!(liar.play(30), GuessResult::TooHigh);
+        assert_eq!(liar.bounds(), (1, 29));
+        assert_eq!(liar.play(80), GuessResult::TooLow);
+        assert_eq!(liar.bounds(), (81, 100));
+        assert_eq!(liar.history[2].low, 81);
+
+        let mut game = Game {
+            secret_number: 50,
+            ..Game::from_seed(
+                7,
+                GameConfig {
+                    lives: 1000,
+                    lie_chance: 0.3,
+                    ..config
+                },
+            )
+        };
+        for guess in (1..=100)
+            .cycle()
+            .step_by(7)
+            .filter(|&guess| guess != 50)
+            .take(500)
+        {
+            let result = game.play(guess);
+            let (low, high) = game.bounds();
+            assert!(low <= high);
+            match result {
+                GuessResult::TooHigh if guess > 1 => assert!(high < guess),
+                GuessResult::TooLow if guess < 100 => assert!(low > guess),
+                _ => {}
+            }
+        }
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_sample_secret_positions() {
+        let secrets = sample_secret_positions(1, 100, 0..10_000);
+        assert_eq!(secrets.len(), 10_000);
+        assert!(secrets.iter().all(|secret| (1..=100).contains(secret)));
+
+        let count = secrets.len() as f64;
+        let mean = secrets.iter().map(|&secret| f64::from(secret)).sum::<f64>() / count;
+        let variance = secrets
+            .iter()
+            .map(|&secret| (f64::from(secret) - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        assert!((mean - 50.5).abs() < 1.5, "mean {mean}");
+        assert!(
+            (variance / 833.25 - 1.0).abs() < 0.05,
+            "variance {variance}"
+        );
+
+        let stuck = sample_secret_positions(1, 100, std::iter::repeat(7).take(100));
+        assert!(stuck.iter().all(|&secret| secret == stuck[0]));
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_oracle_secret() {
+        for seed in 0..20 {
+            let mut game = Game::from_seed(seed, GameConfig::default());
+            let secret = game.oracle_secret();
+            assert_eq!(game.reveal(), None);
+            assert_eq!(game.play(secret), GuessResult::Correct);
+            assert_eq!(game.state(), GameState::Won);
+            assert_eq!(game.oracle_secret(), secret);
+        }
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_forced_outcomes() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 6,
+            ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+        };
+        game.play(2);
+        assert!(!game.is_over());
+        assert_eq!(game.reveal(), None);
+
+        game.force_win();
+        assert_eq!(game.state(), GameState::Won);
+        assert!(game.is_over());
+        assert_eq!(game.reveal(), Some(6));
+        assert_eq!(
+            game.outcome(),
+            Some(GameOutcome::Won {
+                attempts: 2,
+                score: 250
+            })
+        );
+
+        let mut game = Game {
+            secret_number: 6,
+            ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+        };
+        game.force_lose();
+        assert_eq!(game.state(), GameState::Lost);
+        assert!(game.is_over());
+        assert_eq!(game.reveal(), Some(6));
+        assert_eq!(
+            game.outcome(),
+            Some(GameOutcome::Lost {
+                attempts: 0,
+                secret: 6
+            })
+        );
+    }
+}
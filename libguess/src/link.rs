@@ -0,0 +1,489 @@
+use crate::{Game, GameConfig, GameError, RngKind, SecretDistribution};
+use rand::rngs::StdRng;
+use std::collections::HashMap;
+
+/// The settings a link can hold, in the order [`Game::share_url`] writes them.
+const KEYS: [&str; 20] = [
+    "min",
+    "max",
+    "lives",
+    "lie_chance",
+    "free_first_guess",
+    "avoid_obvious",
+    "cold_start",
+    "cold_start_counts",
+    "tutorial",
+    "free_hints",
+    "rng_kind",
+    "history_cap",
+    "out_of_range_penalty",
+    "max_score",
+    "require_spare_life",
+    "mercy_rule",
+    "hint_budget",
+    "quartile_hints",
+    "seed",
+    "secret",
+];
+
+impl Game {
+    /// Builds a link to this puzzle by adding its settings to `base` as query parameters, so
+    /// players can share the exact same game.
+    ///
+    /// The link holds every [`GameConfig`] setting that differs from the default, and then the
+    /// seed for a seeded game or the secret itself otherwise. Every value is percent-encoded, and
+    /// a `base` that already has a query keeps it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::Unshareable` if the game has a setting outside [`GameConfig`] that the
+    /// link cannot hold: an announced range, excluded numbers, a non-uniform
+    /// [`SecretDistribution`] or life regeneration. The comparison strategy cannot be seen from
+    /// the game, so it is never shared and the link always plays with [`HighLow`](crate::HighLow).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameConfig};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let game = Game::from_seed(42, GameConfig::default());
+    /// let url = game.share_url("https://example.com/play").unwrap();
+    /// assert_eq!(url, "https://example.com/play?min=1&max=20&lives=10&seed=42");
+    /// let shared = Game::from_url(&url, &mut StdRng::from_seed(Default::default())).unwrap();
+    /// assert_eq!(shared.secret_number, game.secret_number);
+    /// ```
+    pub fn share_url(&self, base: &str) -> Result<String, GameError> {
+        if self.announced_range.is_some()
+            || !self.excluded.is_empty()
+            || self.distribution != SecretDistribution::Uniform
+            || self.regen_cooldown.is_some()
+        {
+            return Err(GameError::Unshareable);
+        }
+
+        let mut params = vec![
+            ("min", self.min_num.to_string()),
+            ("max", self.max_num.to_string()),
+            ("lives", self.initial_lives.to_string()),
+        ];
+        if self.lie_chance > 0.0 {
+            params.push(("lie_chance", self.lie_chance.to_string()));
+        }
+        for (key, set) in [
+            ("free_first_guess", self.free_first_guess),
+            ("avoid_obvious", self.avoid_obvious),
+            ("cold_start", self.cold_start),
+            ("cold_start_counts", self.cold_start_counts),
+            ("tutorial", self.tutorial),
+            ("free_hints", self.free_hints),
+        ] {
+            if set {
+                params.push((key, "true".to_string()));
+            }
+        }
+        if self.rng_kind != RngKind::Std {
+            params.push(("rng_kind", rng_name(self.rng_kind).to_string()));
+        }
+        if let Some(cap) = self.history_cap {
+            params.push(("history_cap", cap.to_string()));
+        }
+        if self.out_of_range_penalty > 0 {
+            params.push((
+                "out_of_range_penalty",
+                self.out_of_range_penalty.to_string(),
+            ));
+        }
+        if let Some(max_score) = self.max_score {
+            params.push(("max_score", max_score.to_string()));
+        }
+        for (key, set) in [
+            ("require_spare_life", self.require_spare_life),
+            ("mercy_rule", self.mercy_rule),
+        ] {
+            if set {
+                params.push((key, "true".to_string()));
+            }
+        }
+        if let Some(budget) = self.initial_hint_budget {
+            params.push(("hint_budget", budget.to_string()));
+        }
+        if self.quartile_hints {
+            params.push(("quartile_hints", "true".to_string()));
+        }
+        match self.seed {
+            Some(seed) => params.push(("seed", seed.to_string())),
+            None => params.push(("secret", self.secret_number.to_string())),
+        }
+
+        let query = params
+            .iter()
+            .map(|(key, value)| format!("{key}={}", percent_encode(value)))
+            .collect::<Vec<_>>()
+            .join("&");
+        let separator = if !base.contains('?') {
+            "?"
+        } else if base.ends_with('?') || base.ends_with('&') {
+            ""
+        } else {
+            "&"
+        };
+        Ok(format!("{base}{separator}{query}"))
+    }
+
+    /// Creates the game a link from [`Game::share_url`] describes.
+    ///
+    /// A seeded link replays the seed exactly, so `rng` is only used for links that carry the
+    /// secret itself. Parameters this method does not know, such as tracking tags, are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidUrl` if a setting is missing, repeated or malformed, and
+    /// `GameError::InvalidRange` if the range is empty or does not contain the shared secret.
+    pub fn from_url(url: &str, rng: &mut StdRng) -> Result<Self, GameError> {
+        let query = url.split('#').next().unwrap_or_default();
+        let query = query.split_once('?').map_or("", |(_, query)| query);
+
+        let mut link = HashMap::new();
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = percent_decode(value).ok_or(GameError::InvalidUrl)?;
+            let key = percent_decode(key).ok_or(GameError::InvalidUrl)?;
+            let key = match KEYS.iter().find(|&&known| known == key) {
+                Some(&key) => key,
+                None => continue,
+            };
+            if link.insert(key, value).is_some() {
+                return Err(GameError::InvalidUrl);
+            }
+        }
+
+        let rng_kind = match link.remove("rng_kind") {
+            Some(name) => rng_kind(&name).ok_or(GameError::InvalidUrl)?,
+            None => RngKind::Std,
+        };
+        let flag = |link: &mut HashMap<&str, String>, key| {
+            optional(link.remove(key)).map(|set| set.unwrap_or(false))
+        };
+        let config = GameConfig {
+            min_num: required(link.remove("min"))?,
+            max_num: required(link.remove("max"))?,
+            lives: required(link.remove("lives"))?,
+            cold_start: flag(&mut link, "cold_start")?,
+            cold_start_counts: flag(&mut link, "cold_start_counts")?,
+            avoid_obvious: flag(&mut link, "avoid_obvious")?,
+            lie_chance: optional(link.remove("lie_chance"))?.unwrap_or(0.0),
+            free_first_guess: flag(&mut link, "free_first_guess")?,
+            tutorial: flag(&mut link, "tutorial")?,
+            free_hints: flag(&mut link, "free_hints")?,
+            rng_kind,
+            history_cap: optional(link.remove("history_cap"))?,
+            out_of_range_penalty: optional(link.remove("out_of_range_penalty"))?.unwrap_or(0),
+            max_score: optional(link.remove("max_score"))?,
+            require_spare_life: flag(&mut link, "require_spare_life")?,
+            mercy_rule: flag(&mut link, "mercy_rule")?,
+            hint_budget: optional(link.remove("hint_budget"))?,
+            quartile_hints: flag(&mut link, "quartile_hints")?,
+        };
+        if config.min_num > config.max_num {
+            return Err(GameError::InvalidRange);
+        }
+
+        match (
+            optional(link.remove("seed"))?,
+            optional(link.remove("secret"))?,
+        ) {
+            (Some(seed), None) => Ok(Game::from_seed(seed, config)),
+            (None, Some(secret)) if (config.min_num..=config.max_num).contains(&secret) => {
+                // The cold start's first guess is answered against the secret, so it waits until
+                // the shared one is in place.
+                let mut game = Game {
+                    secret_number: secret,
+                    ..Game::from_config(
+                        GameConfig {
+                            cold_start: false,
+                            ..config
+                        },
+                        rng,
+                    )
+                };
+                if config.cold_start {
+                    game.play_cold_start();
+                }
+                Ok(game)
+            }
+            (None, Some(_)) => Err(GameError::InvalidRange),
+            _ => Err(GameError::InvalidUrl),
+        }
+    }
+}
+
+/// Returns the name a link gives `kind`.
+fn rng_name(kind: RngKind) -> &'static str {
+    match kind {
+        RngKind::Std => "std",
+        RngKind::SmallFast => "small_fast",
+        RngKind::ChaCha => "chacha",
+    }
+}
+
+/// Returns the generator kind a link names, the reverse of [`rng_name`].
+fn rng_kind(name: &str) -> Option<RngKind> {
+    [RngKind::Std, RngKind::SmallFast, RngKind::ChaCha]
+        .into_iter()
+        .find(|&kind| rng_name(kind) == name)
+}
+
+/// Parses a setting the link must have.
+fn required<T: std::str::FromStr>(value: Option<String>) -> Result<T, GameError> {
+    optional(value)?.ok_or(GameError::InvalidUrl)
+}
+
+/// Parses a setting the link may leave out.
+fn optional<T: std::str::FromStr>(value: Option<String>) -> Result<Option<T>, GameError> {
+    value
+        .map(|value| value.parse().map_err(|_| GameError::InvalidUrl))
+        .transpose()
+}
+
+/// Percent-encodes everything in `value` but the characters URLs never reserve: letters, digits
+/// and `-._~`.
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+/// Decodes `%XX` escapes in `value`, or returns `None` if an escape is malformed or the result is
+/// not UTF-8.
+fn percent_decode(value: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut rest = value.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = tail
+                .get(..2)
+                .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))?;
+            bytes.push(u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RngKind;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_share_url_round_trip() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let config = GameConfig {
+            min_num: 5,
+            max_num: 5000,
+            lives: 14,
+            lie_chance: 0.25,
+            free_first_guess: true,
+            avoid_obvious: true,
+            ..GameConfig::default()
+        };
+        let seeded = Game::from_seed(99, config);
+        let url = seeded
+            .share_url("https://example.com/play?lang=en")
+            .unwrap();
+        assert_eq!(
+            url,
+            "https://example.com/play?lang=en&min=5&max=5000&lives=14&lie_chance=0.25\
+             &free_first_guess=true&avoid_obvious=true&seed=99"
+        );
+        let shared = Game::from_url(&url, &mut rng).unwrap();
+        assert_eq!(shared.secret_number, seeded.secret_number);
+        assert_eq!((shared.min_num, shared.max_num), (5, 5000));
+        assert_eq!(shared.lives, 14);
+        assert_eq!(shared.lie_chance, 0.25);
+        assert!(shared.free_first_guess);
+        assert!(shared.avoid_obvious);
+        assert_eq!(shared.seed, Some(99));
+
+        let unseeded = Game {
+            secret_number: 17,
+            ..Game::new(Some(1), Some(20), Some(4), &mut rng)
+        };
+        let url = unseeded.share_url("https://example.com/play?").unwrap();
+        assert_eq!(
+            url,
+            "https://example.com/play?min=1&max=20&lives=4&secret=17"
+        );
+        assert_eq!(Game::from_url(&url, &mut rng).unwrap().secret_number, 17);
+
+        let chacha = Game::from_seed(
+            7,
+            GameConfig {
+                rng_kind: RngKind::ChaCha,
+                ..GameConfig::default()
+            },
+        );
+        let url = chacha.share_url("").unwrap();
+        assert_eq!(url, "?min=1&max=20&lives=10&rng_kind=chacha&seed=7");
+        let shared = Game::from_url(&url, &mut rng).unwrap();
+        assert_eq!(shared.secret_number, chacha.secret_number);
+        assert_eq!(shared.rng_kind, RngKind::ChaCha);
+    }
+
+    /// Returns the settings `game` was made with, as far as the game still holds them.
+    fn config_of(game: &Game) -> GameConfig {
+        GameConfig {
+            min_num: game.min_num,
+            max_num: game.max_num,
+            lives: game.initial_lives,
+            cold_start: game.cold_start,
+            cold_start_counts: game.cold_start_counts,
+            avoid_obvious: game.avoid_obvious,
+            lie_chance: game.lie_chance,
+            free_first_guess: game.free_first_guess,
+            tutorial: game.tutorial,
+            free_hints: game.free_hints,
+            rng_kind: game.rng_kind,
+            history_cap: game.history_cap,
+            out_of_range_penalty: game.out_of_range_penalty,
+            max_score: game.max_score,
+            require_spare_life: game.require_spare_life,
+            mercy_rule: game.mercy_rule,
+            hint_budget: game.initial_hint_budget,
+            quartile_hints: game.quartile_hints,
+        }
+    }
+
+    #[test]
+    fn test_share_url_every_setting() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let config = GameConfig {
+            min_num: 5,
+            max_num: 500,
+            lives: 12,
+            cold_start: true,
+            cold_start_counts: true,
+            avoid_obvious: true,
+            lie_chance: 0.25,
+            free_first_guess: true,
+            tutorial: true,
+            free_hints: true,
+            rng_kind: RngKind::SmallFast,
+            history_cap: Some(6),
+            out_of_range_penalty: 2,
+            max_score: Some(900),
+            require_spare_life: true,
+            mercy_rule: true,
+            hint_budget: Some(3),
+            quartile_hints: true,
+        };
+
+        let mut seeded = Game::from_seed(99, config);
+        seeded.hint_half();
+        assert_eq!(seeded.hint_budget, Some(2));
+        let url = seeded.share_url("").unwrap();
+        assert_eq!(
+            url,
+            "?min=5&max=500&lives=12&lie_chance=0.25&free_first_guess=true&avoid_obvious=true\
+             &cold_start=true&cold_start_counts=true&tutorial=true&free_hints=true\
+             &rng_kind=small_fast&history_cap=6&out_of_range_penalty=2&max_score=900\
+             &require_spare_life=true&mercy_rule=true&hint_budget=3&quartile_hints=true&seed=99"
+        );
+        let shared = Game::from_url(&url, &mut rng).unwrap();
+        assert_eq!(config_of(&shared), config);
+        assert_eq!(shared.secret_number, seeded.secret_number);
+        assert_eq!(shared.hint_budget, Some(3));
+
+        let unseeded = Game {
+            seed: None,
+            ..Game::from_seed(99, config)
+        };
+        let url = unseeded.share_url("").unwrap();
+        assert!(url.ends_with(&format!("&secret={}", unseeded.secret_number)));
+        let shared = Game::from_url(&url, &mut rng).unwrap();
+        assert_eq!(config_of(&shared), config);
+        assert_eq!(shared.secret_number, unseeded.secret_number);
+        assert_eq!(shared.bounds(), unseeded.bounds());
+        assert_eq!(shared.history, unseeded.history);
+        assert_eq!(shared.attempts, 1);
+    }
+
+    #[test]
+    fn test_share_url_unshareable() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut announced = Game::from_seed(1, GameConfig::default());
+        announced.with_announced_range(1, 50).unwrap();
+        let excluded = Game::new_excluding(None, None, None, &[3], &mut rng).unwrap();
+        let skewed = Game {
+            distribution: SecretDistribution::Triangular,
+            ..Game::from_seed(1, GameConfig::default())
+        };
+        let regenerating = Game {
+            regen_cooldown: Some(std::time::Duration::from_secs(5)),
+            ..Game::from_seed(1, GameConfig::default())
+        };
+        for game in [announced, excluded, skewed, regenerating] {
+            assert_eq!(game.share_url(""), Err(GameError::Unshareable));
+        }
+    }
+
+    #[test]
+    fn test_percent_encoding() {
+        assert_eq!(percent_encode("a b&c=d/é~"), "a%20b%26c%3Dd%2F%C3%A9~");
+        assert_eq!(
+            percent_decode(&percent_encode("a b&c=d/é~")).as_deref(),
+            Some("a b&c=d/é~")
+        );
+        assert_eq!(percent_decode("%4"), None);
+        assert_eq!(percent_decode("%zz"), None);
+        assert_eq!(percent_decode("%+1"), None);
+        assert_eq!(percent_decode("%FF"), None);
+
+        let mut rng = StdRng::from_seed(Default::default());
+        let url = "https://example.com/?utm_source=a%20b&%6Din=1&max=1%30&lives=3&seed=%34#top";
+        let game = Game::from_url(url, &mut rng).unwrap();
+        assert_eq!((game.min_num, game.max_num), (1, 10));
+        assert_eq!(game.seed, Some(4));
+    }
+
+    #[test]
+    fn test_from_url_invalid() {
+        let mut rng = StdRng::from_seed(Default::default());
+        for (url, error) in [
+            ("https://example.com/play", GameError::InvalidUrl),
+            ("?min=1&max=20&seed=3", GameError::InvalidUrl),
+            ("?min=1&max=20&lives=5", GameError::InvalidUrl),
+            (
+                "?min=1&max=20&lives=5&seed=3&secret=4",
+                GameError::InvalidUrl,
+            ),
+            ("?min=1&min=2&max=20&lives=5&seed=3", GameError::InvalidUrl),
+            ("?min=one&max=20&lives=5&seed=3", GameError::InvalidUrl),
+            ("?min=1&max=20&lives=5&seed=%G1", GameError::InvalidUrl),
+            (
+                "?min=1&max=20&lives=5&rng_kind=mt&seed=3",
+                GameError::InvalidUrl,
+            ),
+            (
+                "?min=1&max=20&lives=5&tutorial=yes&seed=3",
+                GameError::InvalidUrl,
+            ),
+            ("?min=30&max=20&lives=5&seed=3", GameError::InvalidRange),
+            ("?min=1&max=20&lives=5&secret=21", GameError::InvalidRange),
+        ] {
+            assert_eq!(Game::from_url(url, &mut rng).err(), Some(error), "{url}");
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! Thin wrappers over the `log` macros that compile to nothing without the `logging` feature.
+//!
+//! The secret number must only ever be passed to `log_trace!`, so it cannot leak through the
+//! debug-level records that downstream apps are likely to enable.
+
+#[cfg(feature = "logging")]
+macro_rules! log_debug {
+    ($($arg:tt)*) => { log::debug!($($arg)*) };
+}
+
+#[cfg(not(feature = "logging"))]
+macro_rules! log_debug {
+    ($($arg:tt)*) => {{
+        let _ = format_args!($($arg)*);
+    }};
+}
+
+#[cfg(feature = "logging")]
+macro_rules! log_trace {
+    ($($arg:tt)*) => { log::trace!($($arg)*) };
+}
+
+#[cfg(not(feature = "logging"))]
+macro_rules! log_trace {
+    ($($arg:tt)*) => {{
+        let _ = format_args!($($arg)*);
+    }};
+}
@@ -0,0 +1,357 @@
+use crate::{compare, Game, GameError, GuessResult};
+
+/// Works out where the secret probably is when `TooHigh` and `TooLow` answers may be lies, as in
+/// a game with a `lie_chance`.
+///
+/// It keeps a probability for every number in the range, so it only accepts ranges small enough
+/// to hold one `f64` per number. Each answer makes the numbers it agrees with more likely, by how
+/// much more often the game tells the truth than lies. `Correct` is never a lie.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoisyReasoner {
+    min_num: u32,
+    lie_chance: f64,
+    weights: Vec<f64>,
+}
+
+impl NoisyReasoner {
+    /// The most numbers [`NoisyReasoner::new`] keeps a probability for, about 8 MB of them.
+    pub const MAX_CANDIDATES: u64 = 1 << 20;
+
+    /// Creates a reasoner for a secret from `min_num..=max_num` whose direction answers are lies
+    /// with probability `lie_chance`, starting with every number equally likely.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidRange` if `min_num` is greater than `max_num`, or
+    /// `GameError::RangeTooLargeToEnumerate` if the range holds more than `MAX_CANDIDATES`
+    /// numbers.
+    pub fn new(min_num: u32, max_num: u32, lie_chance: f64) -> Result<Self, GameError> {
+        Self::with_max_candidates(min_num, max_num, lie_chance, Self::MAX_CANDIDATES)
+    }
+
+    /// Creates a reasoner like [`NoisyReasoner::new`], allowing up to `max_candidates` numbers
+    /// in the range.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidRange` if `min_num` is greater than `max_num`, or
+    /// `GameError::RangeTooLargeToEnumerate` if the range holds more than `max_candidates`
+    /// numbers. Nothing is allocated for a range that is too large.
+    pub fn with_max_candidates(
+        min_num: u32,
+        max_num: u32,
+        lie_chance: f64,
+        max_candidates: u64,
+    ) -> Result<Self, GameError> {
+        if min_num > max_num {
+            return Err(GameError::InvalidRange);
+        }
+        let count = u64::from(max_num - min_num) + 1;
+        if count > max_candidates {
+            return Err(GameError::RangeTooLargeToEnumerate);
+        }
+
+        let count = usize::try_from(count).map_err(|_| GameError::RangeTooLargeToEnumerate)?;
+        Ok(Self {
+            min_num,
+            lie_chance: lie_chance.clamp(0.0, 1.0),
+            weights: vec![1.0 / count as f64; count],
+        })
+    }
+
+    /// Updates the probabilities with the answer `reported` for `guess`.
+    ///
+    /// `NoMoreLives`, `Excluded` and `MercyLoss` tell nothing about the secret and are ignored, as
+    /// is an answer that contradicts everything seen so far. `Quartile` answers are never lies and
+    /// are ignored too.
+    pub fn update(&mut self, guess: u32, reported: GuessResult) {
+        let reported = match reported {
+            GuessResult::NoMoreLives
+            | GuessResult::Excluded
+            | GuessResult::MercyLoss
+            | GuessResult::Quartile(_) => return,
+            GuessResult::Draw => GuessResult::Correct,
+            reported => reported,
+        };
+
+        let min_num = self.min_num;
+        let max_num = min_num + (self.weights.len() - 1) as u32;
+        let lie_chance = self.lie_chance;
+        let updated: Vec<f64> = self
+            .weights
+            .iter()
+            .zip(min_num..=max_num)
+            .map(|(&weight, candidate)| {
+                let likelihood = match (compare(guess, candidate), reported) {
+                    (GuessResult::Correct, GuessResult::Correct) => 1.0,
+                    (GuessResult::Correct, _) | (_, GuessResult::Correct) => 0.0,
+                    (truth, reported) if truth == reported => 1.0 - lie_chance,
+                    _ => lie_chance,
+                };
+                weight * likelihood
+            })
+            .collect();
+        let total: f64 = updated.iter().sum();
+        if total > 0.0 {
+            self.weights = updated.into_iter().map(|weight| weight / total).collect();
+        }
+    }
+
+    /// Returns the chance that `candidate` is the secret.
+    pub fn probability(&self, candidate: u32) -> f64 {
+        candidate
+            .checked_sub(self.min_num)
+            .and_then(|offset| self.weights.get(offset as usize))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the number most likely to be the secret, the lowest one on a tie.
+    pub fn most_likely(&self) -> u32 {
+        let (offset, _) =
+            self.weights
+                .iter()
+                .enumerate()
+                .fold((0, f64::MIN), |best, (offset, &weight)| {
+                    if weight > best.1 {
+                        (offset, weight)
+                    } else {
+                        best
+                    }
+                });
+        self.min_num + offset as u32
+    }
+
+    /// Returns a good next guess: the number that splits the probability in half.
+    pub fn suggest_guess(&self) -> u32 {
+        let mut seen = 0.0;
+        for (offset, weight) in self.weights.iter().enumerate() {
+            seen += weight;
+            if seen >= 0.5 {
+                return self.min_num + offset as u32;
+            }
+        }
+        self.most_likely()
+    }
+
+    /// Returns the shortest run of numbers that holds the secret with at least `confidence`,
+    /// clamped to 0.0..=1.0, preferring the likelier run when several are as short.
+    pub fn credible_interval(&self, confidence: f64) -> (u32, u32) {
+        let mut prefix = Vec::with_capacity(self.weights.len() + 1);
+        prefix.push(0.0);
+        for weight in &self.weights {
+            prefix.push(prefix[prefix.len() - 1] + weight);
+        }
+        let needed = confidence.clamp(0.0, 1.0) * prefix[self.weights.len()];
+
+        let mut best = (0, self.weights.len() - 1);
+        let mut best_mass = f64::MIN;
+        let mut end = 0;
+        for start in 0..self.weights.len() {
+            end = end.max(start);
+            while end + 1 < self.weights.len() && prefix[end + 1] - prefix[start] < needed {
+                end += 1;
+            }
+            let mass = prefix[end + 1] - prefix[start];
+            if mass < needed {
+                break;
+            }
+            let shorter = end - start < best.1 - best.0;
+            if shorter || (end - start == best.1 - best.0 && mass > best_mass) {
+                best = (start, end);
+                best_mass = mass;
+            }
+        }
+        (self.min_num + best.0 as u32, self.min_num + best.1 as u32)
+    }
+}
+
+impl Game {
+    /// Returns a run of numbers that holds the secret with at least `confidence`, judging only by
+    /// the answers the player was given.
+    ///
+    /// When answers never lie the bounds are certain, so this is just the bounds. With a
+    /// `lie_chance` the bounds follow the answers as reported, and a single lie can lead them
+    /// astray, so instead every answer so far is weighed over the announced range as a
+    /// [`NoisyReasoner`] would, and the shortest run reaching `confidence` is returned. A range
+    /// holding more than [`NoisyReasoner::MAX_CANDIDATES`] numbers is returned whole.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameConfig, GameTrait};
+    ///
+    /// let config = GameConfig {
+    ///     min_num: 1,
+    ///     max_num: 100,
+    ///     lives: 50,
+    ///     lie_chance: 0.2,
+    ///     ..GameConfig::default()
+    /// };
+    /// let mut game = Game { secret_number: 30, ..Game::from_seed(3, config) };
+    /// for _ in 0..10 {
+    ///     game.play(50);
+    /// }
+    /// let (low, high) = game.credible_interval(0.9);
+    /// assert!(high < 50);
+    /// ```
+    pub fn credible_interval(&self, confidence: f64) -> (u32, u32) {
+        if self.lie_chance <= 0.0 {
+            return (self.low, self.high);
+        }
+        let (min_num, max_num) = self.announced_range();
+        let mut reasoner = match NoisyReasoner::new(min_num, max_num, self.lie_chance) {
+            Ok(reasoner) => reasoner,
+            Err(_) => return (min_num, max_num),
+        };
+        for record in &self.history {
+            reasoner.update(record.guess, record.result);
+        }
+        reasoner.credible_interval(confidence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Game, GameConfig, GameTrait};
+
+    fn noisy_game(secret: u32, seed: u64) -> Game {
+        let config = GameConfig {
+            min_num: 1,
+            max_num: 100,
+            lives: 10_000,
+            lie_chance: 0.2,
+            ..GameConfig::default()
+        };
+        Game {
+            secret_number: secret,
+            ..Game::from_seed(seed, config)
+        }
+    }
+
+    #[test]
+    fn test_converges_on_fixed_guesses() {
+        let mut game = noisy_game(37, 1);
+        let mut reasoner = NoisyReasoner::new(1, 100, 0.2).unwrap();
+        for _ in 0..10 {
+            for guess in (1..=100).filter(|&guess| guess != 37) {
+                reasoner.update(guess, game.play(guess));
+            }
+        }
+        assert_eq!(reasoner.most_likely(), 37);
+        assert!(reasoner.probability(37) > 0.5);
+    }
+
+    #[test]
+    fn test_finds_secret_by_suggestions() {
+        for (secret, seed) in [(1, 2), (37, 3), (64, 4), (100, 5)] {
+            let mut game = noisy_game(secret, seed);
+            let mut reasoner = NoisyReasoner::new(1, 100, 0.2).unwrap();
+            let mut found = false;
+            for _ in 0..200 {
+                let guess = reasoner.suggest_guess();
+                let result = game.play(guess);
+                reasoner.update(guess, result);
+                if result == GuessResult::Correct {
+                    found = true;
+                    break;
+                }
+            }
+            assert!(found, "secret {secret} not found");
+            assert_eq!(reasoner.most_likely(), secret);
+            assert_eq!(reasoner.probability(secret), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_truthful_answers() {
+        let mut reasoner = NoisyReasoner::new(1, 10, 0.0).unwrap();
+        reasoner.update(5, GuessResult::TooLow);
+        reasoner.update(8, GuessResult::TooHigh);
+        assert_eq!(reasoner.probability(4), 0.0);
+        assert_eq!(reasoner.probability(6), 0.5);
+        assert_eq!(reasoner.probability(7), 0.5);
+
+        reasoner.update(9, GuessResult::TooLow);
+        assert_eq!(reasoner.probability(6), 0.5);
+        assert_eq!(reasoner.probability(11), 0.0);
+    }
+
+    #[test]
+    fn test_credible_interval() {
+        let mut reasoner = NoisyReasoner::new(1, 10, 0.25).unwrap();
+        assert_eq!(reasoner.credible_interval(1.0), (1, 10));
+        for _ in 0..3 {
+            reasoner.update(5, GuessResult::TooHigh);
+        }
+        assert_eq!(reasoner.credible_interval(0.9), (1, 4));
+        assert_eq!(reasoner.credible_interval(0.99), (1, 9));
+        assert_eq!(reasoner.credible_interval(1.0), (1, 10));
+        assert_eq!(
+            reasoner.credible_interval(0.0).0,
+            reasoner.credible_interval(0.0).1
+        );
+
+        reasoner.update(3, GuessResult::Correct);
+        assert_eq!(reasoner.credible_interval(0.5), (3, 3));
+    }
+
+    #[test]
+    fn test_game_credible_interval() {
+        let mut rng = rand::SeedableRng::from_seed(Default::default());
+        let mut exact = Game {
+            secret_number: 37,
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        exact.play(50);
+        exact.play(25);
+        assert_eq!(exact.credible_interval(0.5), (26, 49));
+        assert_eq!(exact.credible_interval(0.99), (26, 49));
+
+        let mut noisy = noisy_game(37, 6);
+        assert_eq!(noisy.credible_interval(1.0), (1, 100));
+        let (low, high) = noisy.credible_interval(0.9);
+        assert_eq!(high - low + 1, 90);
+        for _ in 0..10 {
+            for guess in [20, 30, 40, 50] {
+                noisy.play(guess);
+            }
+        }
+        let (low, high) = noisy.credible_interval(0.95);
+        assert_eq!((low, high), (31, 39));
+        let (narrow_low, narrow_high) = noisy.credible_interval(0.5);
+        assert!(narrow_low >= low && narrow_high <= high);
+
+        let lied = noisy
+            .history
+            .iter()
+            .filter(|record| record.result != compare(record.guess, 37))
+            .count();
+        assert!(lied > 0);
+    }
+
+    #[test]
+    fn test_range_ending_at_max() {
+        let mut reasoner = NoisyReasoner::new(u32::MAX - 10, u32::MAX, 0.1).unwrap();
+        reasoner.update(u32::MAX - 5, GuessResult::TooHigh);
+        assert!(reasoner.probability(u32::MAX - 6) > reasoner.probability(u32::MAX));
+        reasoner.update(u32::MAX, GuessResult::Correct);
+        assert_eq!(reasoner.most_likely(), u32::MAX);
+        assert_eq!(reasoner.credible_interval(0.5), (u32::MAX, u32::MAX));
+    }
+
+    #[test]
+    fn test_range_too_large() {
+        assert_eq!(
+            NoisyReasoner::new(0, u32::MAX, 0.1),
+            Err(GameError::RangeTooLargeToEnumerate)
+        );
+        assert_eq!(
+            NoisyReasoner::with_max_candidates(1, 100, 0.1, 99),
+            Err(GameError::RangeTooLargeToEnumerate)
+        );
+        assert!(NoisyReasoner::with_max_candidates(1, 100, 0.1, 100).is_ok());
+        assert_eq!(NoisyReasoner::new(10, 1, 0.1), Err(GameError::InvalidRange));
+    }
+}
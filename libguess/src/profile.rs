@@ -0,0 +1,201 @@
+use crate::GameOutcome;
+
+/// Lifetime totals for a single player, built up from finished games.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Profile {
+    pub name: String,
+    pub games_played: u32,
+    pub games_won: u32,
+    pub total_attempts: u32,
+    pub best_score: u32,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    /// Attempts spent on won games only, for [`Profile::average_attempts_per_win`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub won_attempts: u32,
+}
+
+impl Profile {
+    /// Creates an empty profile for the named player.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a finished game to the lifetime totals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{GameOutcome, Profile};
+    ///
+    /// let mut profile = Profile::new("Ada");
+    /// profile.record(&GameOutcome::Won { attempts: 3, score: 200 });
+    /// assert_eq!(profile.games_won, 1);
+    /// assert_eq!(profile.best_score, 200);
+    /// ```
+    pub fn record(&mut self, outcome: &GameOutcome) {
+        self.games_played += 1;
+        match *outcome {
+            GameOutcome::Won { attempts, score } => {
+                self.games_won += 1;
+                self.total_attempts += attempts;
+                self.won_attempts += attempts;
+                self.best_score = self.best_score.max(score);
+                self.current_streak += 1;
+                self.best_streak = self.best_streak.max(self.current_streak);
+            }
+            GameOutcome::Lost { attempts, .. } | GameOutcome::Drawn { attempts } => {
+                self.total_attempts += attempts;
+                self.current_streak = 0;
+            }
+        }
+    }
+
+    /// Adds the totals from `other`, such as the same player's profile from another machine,
+    /// keeping this profile's name.
+    ///
+    /// Streaks cannot simply be added, so `other`'s games are taken as played after this
+    /// profile's. If every one of them was a win, they carry on the current streak; otherwise the
+    /// current streak is `other`'s. The best streak is the longest of the two and any streak
+    /// carried across. A streak that runs across the join and then breaks within `other` is not
+    /// recorded anywhere, so it cannot count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{GameOutcome, Profile};
+    ///
+    /// let mut home = Profile::new("Ada");
+    /// home.record(&GameOutcome::Won { attempts: 3, score: 200 });
+    /// let mut work = Profile::new("Ada");
+    /// work.record(&GameOutcome::Won { attempts: 5, score: 120 });
+    /// home.merge(&work);
+    /// assert_eq!(home.games_won, 2);
+    /// assert_eq!(home.best_score, 200);
+    /// assert_eq!(home.current_streak, 2);
+    /// ```
+    pub fn merge(&mut self, other: &Profile) {
+        self.games_played += other.games_played;
+        self.games_won += other.games_won;
+        self.total_attempts += other.total_attempts;
+        self.won_attempts += other.won_attempts;
+        self.best_score = self.best_score.max(other.best_score);
+
+        if other.current_streak == other.games_played {
+            self.current_streak += other.current_streak;
+        } else {
+            self.current_streak = other.current_streak;
+        }
+        self.best_streak = self
+            .best_streak
+            .max(other.best_streak)
+            .max(self.current_streak);
+    }
+
+    /// Returns the average number of attempts it took to win a game, or `None` before any win.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{GameOutcome, Profile};
+    ///
+    /// let mut profile = Profile::new("Ada");
+    /// assert_eq!(profile.average_attempts_per_win(), None);
+    /// profile.record(&GameOutcome::Won { attempts: 3, score: 200 });
+    /// profile.record(&GameOutcome::Lost { attempts: 10, secret: 4 });
+    /// profile.record(&GameOutcome::Won { attempts: 6, score: 100 });
+    /// assert_eq!(profile.average_attempts_per_win(), Some(4.5));
+    /// ```
+    pub fn average_attempts_per_win(&self) -> Option<f64> {
+        if self.games_won == 0 {
+            None
+        } else {
+            Some(f64::from(self.won_attempts) / f64::from(self.games_won))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let mut profile = Profile::new("Ada");
+        let outcomes = [
+            GameOutcome::Won {
+                attempts: 4,
+                score: 150,
+            },
+            GameOutcome::Won {
+                attempts: 2,
+                score: 300,
+            },
+            GameOutcome::Lost {
+                attempts: 10,
+                secret: 7,
+            },
+            GameOutcome::Won {
+                attempts: 5,
+                score: 120,
+            },
+        ];
+        for outcome in &outcomes {
+            profile.record(outcome);
+        }
+
+        assert_eq!(profile.games_played, 4);
+        assert_eq!(profile.games_won, 3);
+        assert_eq!(profile.total_attempts, 21);
+        assert_eq!(profile.best_score, 300);
+        assert_eq!(profile.current_streak, 1);
+        assert_eq!(profile.best_streak, 2);
+        assert_eq!(profile.won_attempts, 11);
+        assert_eq!(profile.average_attempts_per_win(), Some(11.0 / 3.0));
+    }
+
+    fn profile_of(outcomes: &[GameOutcome]) -> Profile {
+        let mut profile = Profile::new("Ada");
+        for outcome in outcomes {
+            profile.record(outcome);
+        }
+        profile
+    }
+
+    #[test]
+    fn test_merge() {
+        let won = |attempts, score| GameOutcome::Won { attempts, score };
+        let lost = GameOutcome::Lost {
+            attempts: 10,
+            secret: 7,
+        };
+        let first = [won(4, 150), won(2, 300), lost, won(5, 120)];
+        let second = [lost, won(3, 200), won(6, 90), won(1, 1100)];
+
+        let mut merged = profile_of(&first);
+        merged.merge(&profile_of(&second));
+        let all: Vec<_> = first.iter().chain(&second).copied().collect();
+        assert_eq!(merged, profile_of(&all));
+        assert_eq!(merged.games_played, 8);
+        assert_eq!(merged.games_won, 6);
+        assert_eq!(merged.total_attempts, 41);
+        assert_eq!(merged.won_attempts, 21);
+        assert_eq!(merged.best_score, 1100);
+        assert_eq!(merged.current_streak, 3);
+        assert_eq!(merged.best_streak, 3);
+
+        let mut unbroken = profile_of(&first);
+        let wins = [won(3, 200), won(4, 180)];
+        unbroken.merge(&profile_of(&wins));
+        assert_eq!(unbroken.current_streak, 3);
+        assert_eq!(unbroken.best_streak, 3);
+
+        let mut empty = Profile::new("Ada");
+        empty.merge(&profile_of(&first));
+        assert_eq!(empty, profile_of(&first));
+    }
+}
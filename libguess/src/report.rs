@@ -0,0 +1,171 @@
+use crate::{Game, GameOutcome, GameTrait, GuessResult};
+use std::fmt::Write;
+
+impl Game {
+    /// Writes the game up as a Markdown report for sharing, such as by a teacher: the settings,
+    /// how it ended, a table of every guess with the bounds it left, and the score.
+    ///
+    /// The secret is only named once the game is over. With a `history_cap`, only the guesses
+    /// kept are listed, still numbered by attempt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 7,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// game.play(3);
+    /// let report = game.markdown_report();
+    /// assert!(report.contains("| 1 | 3 | Too low ⬆️ | 4–10 |"));
+    /// assert!(!report.contains("Secret"));
+    /// ```
+    pub fn markdown_report(&self) -> String {
+        let (min_num, max_num) = self.announced_range();
+        let mut report = String::from("# Guess the Number\n\n## Settings\n\n");
+        let _ = writeln!(report, "- Range: {min_num} to {max_num}");
+        let _ = writeln!(report, "- Lives: {}", self.initial_lives);
+        if self.lie_chance > 0.0 {
+            let _ = writeln!(report, "- Lie chance: {}%", self.lie_chance * 100.0);
+        }
+
+        report.push_str("\n## Outcome\n\n");
+        let _ = match self.outcome() {
+            Some(GameOutcome::Won { attempts, .. }) => {
+                writeln!(report, "Won in {attempts} attempts.")
+            }
+            Some(GameOutcome::Lost { attempts, .. }) => {
+                writeln!(report, "Lost after {attempts} attempts.")
+            }
+            Some(GameOutcome::Drawn { attempts }) => {
+                writeln!(report, "Drawn after {attempts} attempts.")
+            }
+            None => writeln!(
+                report,
+                "In progress after {} attempts, with {} lives left.",
+                self.attempts,
+                self.lives()
+            ),
+        };
+        if let Some(secret) = self.reveal() {
+            let _ = writeln!(report, "\nSecret: {secret}");
+        }
+
+        report.push_str("\n## Guesses\n\n");
+        if self.history.is_empty() {
+            report.push_str("No guesses yet.\n");
+        } else {
+            report.push_str("| # | Guess | Result | Bounds after |\n");
+            report.push_str("|---|-------|--------|--------------|\n");
+            let dropped = (self.attempts as usize).saturating_sub(self.history.len());
+            for (i, record) in self.history.iter().enumerate() {
+                let bounds = if record.low == record.high {
+                    record.low.to_string()
+                } else {
+                    format!("{}–{}", record.low, record.high)
+                };
+                let _ = writeln!(
+                    report,
+                    "| {} | {} | {} {} | {bounds} |",
+                    dropped + i + 1,
+                    record.guess,
+                    result_label(record.result),
+                    record.result.emoji()
+                );
+            }
+        }
+
+        let score = match self.outcome() {
+            Some(GameOutcome::Won { score, .. }) => score,
+            _ => 0,
+        };
+        let _ = write!(report, "\n**Final score: {score}**\n");
+        report
+    }
+}
+
+/// Returns how a result reads in a report.
+fn result_label(result: GuessResult) -> &'static str {
+    match result {
+        GuessResult::Correct => "Correct",
+        GuessResult::TooHigh => "Too high",
+        GuessResult::TooLow => "Too low",
+        GuessResult::NoMoreLives => "No more lives",
+        GuessResult::Excluded => "Excluded",
+        GuessResult::Draw => "Draw",
+        GuessResult::MercyLoss => "Ended early",
+        GuessResult::Quartile(1) => "Lowest quarter",
+        GuessResult::Quartile(2) => "Second quarter",
+        GuessResult::Quartile(3) => "Third quarter",
+        GuessResult::Quartile(_) => "Highest quarter",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Game, GameTrait};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_markdown_report() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 70,
+            ..Game::new(Some(1), Some(100), Some(7), &mut rng)
+        };
+        assert!(game.markdown_report().contains("No guesses yet."));
+
+        let guesses = [50, 75, 62, 70];
+        for guess in guesses {
+            game.play(guess);
+        }
+        let report = game.markdown_report();
+        assert!(report.contains("- Range: 1 to 100\n- Lives: 7\n"));
+        assert!(report.contains("Won in 4 attempts.\n\nSecret: 70\n"));
+        assert!(report.contains("| # | Guess | Result | Bounds after |\n|---|"));
+        let rows: Vec<&str> = report
+            .lines()
+            .filter(|line| line.starts_with("| ") && !line.starts_with("| #"))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                "| 1 | 50 | Too low ⬆️ | 51–100 |",
+                "| 2 | 75 | Too high ⬇️ | 51–74 |",
+                "| 3 | 62 | Too low ⬆️ | 63–74 |",
+                "| 4 | 70 | Correct 🎉 | 70 |",
+            ]
+        );
+        assert!(report.ends_with(&format!("**Final score: {}**\n", game.score())));
+
+        let mut lost = Game {
+            secret_number: 70,
+            ..Game::new(Some(1), Some(100), Some(1), &mut rng)
+        };
+        lost.play(10);
+        let report = lost.markdown_report();
+        assert!(report.contains("Lost after 1 attempts."));
+        assert!(report.contains("Secret: 70"));
+        assert!(report.ends_with("**Final score: 0**\n"));
+
+        let mut capped = Game {
+            secret_number: 70,
+            history_cap: Some(2),
+            ..Game::new(Some(1), Some(100), Some(7), &mut rng)
+        };
+        for guess in guesses {
+            capped.play(guess);
+        }
+        let report = capped.markdown_report();
+        assert!(
+            report.contains("\n| 3 | 62 | Too low ⬆️ | 63–74 |\n| 4 | 70 | Correct 🎉 | 70 |\n")
+        );
+        assert!(!report.contains("| 1 |"));
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{midpoint, GameError, GuessResult};
+
+/// The computer's side of a reversed game, where the player picks a number and the computer
+/// guesses it.
+///
+/// The computer always guesses the middle of the numbers still possible. After each guess the
+/// player answers with how the guess compares to their number, using the same `GuessResult`s a
+/// normal game gives the player.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComputerGuesser {
+    low: u32,
+    high: u32,
+    guess: Option<u32>,
+    guesses: u32,
+    found: Option<u32>,
+}
+
+impl ComputerGuesser {
+    /// Creates a guesser for a number the player has picked from `min_num..=max_num`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidRange` if `min_num` is greater than `max_num`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{ComputerGuesser, GuessResult};
+    ///
+    /// let mut computer = ComputerGuesser::new(1, 100).unwrap();
+    /// assert_eq!(computer.guess(), Some(50));
+    /// computer.tell(GuessResult::TooLow);
+    /// assert_eq!(computer.guess(), Some(75));
+    /// ```
+    pub fn new(min_num: u32, max_num: u32) -> Result<Self, GameError> {
+        if min_num > max_num {
+            return Err(GameError::InvalidRange);
+        }
+        Ok(Self {
+            low: min_num,
+            high: max_num,
+            guess: Some(midpoint(min_num, max_num)),
+            guesses: 0,
+            found: None,
+        })
+    }
+
+    /// Returns the guess waiting for the player's answer, or `None` once there is nothing left to
+    /// guess.
+    pub fn guess(&self) -> Option<u32> {
+        self.guess
+    }
+
+    /// Returns the range the player's number is still known to lie in, as `(low, high)`.
+    pub fn bounds(&self) -> (u32, u32) {
+        (self.low, self.high)
+    }
+
+    /// Returns how many guesses the player has answered.
+    pub fn guesses(&self) -> u32 {
+        self.guesses
+    }
+
+    /// Returns the player's number once the computer has guessed it.
+    pub fn found(&self) -> Option<u32> {
+        self.found
+    }
+
+    /// Returns whether the player's answers contradict each other, leaving no number they could
+    /// have picked. This means they lied or made a mistake.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{ComputerGuesser, GuessResult};
+    ///
+    /// let mut computer = ComputerGuesser::new(1, 3).unwrap();
+    /// computer.tell(GuessResult::TooLow);
+    /// assert!(!computer.detect_lie());
+    /// computer.tell(GuessResult::TooLow);
+    /// assert!(computer.detect_lie());
+    /// ```
+    pub fn detect_lie(&self) -> bool {
+        self.guess.is_none() && self.found.is_none()
+    }
+
+    /// Answers the current guess and moves on to the next one.
+    ///
+    /// `TooHigh` and `TooLow` describe the computer's guess relative to the player's number.
+    /// Any other response, or a response when there is no guess waiting, is ignored.
+    pub fn tell(&mut self, response: GuessResult) {
+        let guess = match self.guess {
+            Some(guess) => guess,
+            None => return,
+        };
+
+        match response {
+            GuessResult::Correct => {
+                self.found = Some(guess);
+                self.guess = None;
+            }
+            GuessResult::TooHigh if guess > self.low => self.high = guess - 1,
+            GuessResult::TooLow if guess < self.high => self.low = guess + 1,
+            GuessResult::TooHigh | GuessResult::TooLow => self.guess = None,
+            _ => return,
+        }
+        self.guesses += 1;
+        if self.guess.is_some() {
+            self.guess = Some(midpoint(self.low, self.high));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compare;
+
+    #[test]
+    fn test_converges() {
+        for secret in 1..=100 {
+            let mut computer = ComputerGuesser::new(1, 100).unwrap();
+            while let Some(guess) = computer.guess() {
+                computer.tell(compare(guess, secret));
+            }
+            assert_eq!(computer.found(), Some(secret));
+            assert!(computer.guesses() <= 7);
+        }
+    }
+
+    #[test]
+    fn test_invalid_range() {
+        assert_eq!(ComputerGuesser::new(10, 1), Err(GameError::InvalidRange));
+        assert_eq!(ComputerGuesser::new(5, 5).unwrap().guess(), Some(5));
+    }
+
+    #[test]
+    fn test_detect_lie() {
+        let mut computer = ComputerGuesser::new(1, 10).unwrap();
+        let answers = [
+            GuessResult::TooLow,
+            GuessResult::TooHigh,
+            GuessResult::TooLow,
+            GuessResult::TooHigh,
+        ];
+        for answer in answers {
+            assert!(!computer.detect_lie());
+            computer.tell(answer);
+        }
+        assert!(computer.detect_lie());
+        assert_eq!(computer.guess(), None);
+
+        for secret in 1..=10 {
+            let mut computer = ComputerGuesser::new(1, 10).unwrap();
+            while let Some(guess) = computer.guess() {
+                computer.tell(compare(guess, secret));
+                assert!(!computer.detect_lie());
+            }
+        }
+    }
+}
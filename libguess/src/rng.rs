@@ -0,0 +1,33 @@
+use rand::rngs::{SmallRng, StdRng};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+/// Which random number generator draws a game's secrets, trading speed for quality.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RngKind {
+    /// The standard library-quality generator, [`StdRng`].
+    Std,
+    /// A small, fast generator that is not suitable for cryptography, for running millions of
+    /// trials.
+    SmallFast,
+    /// ChaCha20, a cryptographically secure generator.
+    ChaCha,
+}
+
+impl Default for RngKind {
+    fn default() -> Self {
+        RngKind::Std
+    }
+}
+
+impl RngKind {
+    /// Creates a generator of this kind from `seed`.
+    pub fn seeded(self, seed: u64) -> Box<dyn RngCore + Send> {
+        match self {
+            RngKind::Std => Box::new(StdRng::seed_from_u64(seed)),
+            RngKind::SmallFast => Box::new(SmallRng::seed_from_u64(seed)),
+            RngKind::ChaCha => Box::new(ChaCha20Rng::seed_from_u64(seed)),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::GameOutcome;
+use std::cmp::Reverse;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+/// A single line on the leaderboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScoreEntry {
+    pub name: String,
+    pub score: u32,
+    pub attempts: u32,
+}
+
+impl ScoreEntry {
+    /// Creates an entry for a won game, or returns `None` for a lost or drawn one.
+    pub fn from_outcome(name: &str, outcome: &GameOutcome) -> Option<Self> {
+        match *outcome {
+            GameOutcome::Won { attempts, score } => Some(Self {
+                name: name.to_string(),
+                score,
+                attempts,
+            }),
+            GameOutcome::Lost { .. } | GameOutcome::Drawn { .. } => None,
+        }
+    }
+}
+
+/// The best scores, highest first, persisted as a plain text file.
+///
+/// Each line of the file holds `score`, `attempts` and `name` separated by tabs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Leaderboard {
+    pub entries: Vec<ScoreEntry>,
+}
+
+impl Leaderboard {
+    pub const CAPACITY: usize = 10;
+
+    /// Loads the leaderboard from `path`, starting an empty one if the file does not exist yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or a line is malformed.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err),
+        };
+
+        let mut leaderboard = Self::default();
+        for line in contents.lines().filter(|line| !line.is_empty()) {
+            let mut fields = line.splitn(3, '\t');
+            let mut number = || {
+                fields
+                    .next()
+                    .and_then(|field| field.parse().ok())
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            ErrorKind::InvalidData,
+                            format!("malformed score line: {line}"),
+                        )
+                    })
+            };
+            let score = number()?;
+            let attempts = number()?;
+            let name = fields.next().unwrap_or_default().to_string();
+            leaderboard.submit(ScoreEntry {
+                name,
+                score,
+                attempts,
+            });
+        }
+        Ok(leaderboard)
+    }
+
+    /// Writes the leaderboard to `path`, replacing any previous contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let contents: String = self
+            .entries
+            .iter()
+            .map(|entry| format!("{}\t{}\t{}\n", entry.score, entry.attempts, entry.name))
+            .collect();
+        fs::write(path, contents)
+    }
+
+    /// Adds an entry if it is good enough to make the board.
+    ///
+    /// Entries are ordered by score, then by fewest attempts, and only the best `CAPACITY` are
+    /// kept. Returns the entry's position (0 for first place), or `None` if it did not make it.
+    pub fn submit(&mut self, mut entry: ScoreEntry) -> Option<usize> {
+        entry.name = entry.name.replace(['\t', '\n', '\r'], " ");
+        let rank = |e: &ScoreEntry| (e.score, Reverse(e.attempts));
+        let position = self
+            .entries
+            .iter()
+            .position(|e| rank(&entry) > rank(e))
+            .unwrap_or(self.entries.len());
+        if position >= Self::CAPACITY {
+            return None;
+        }
+        self.entries.insert(position, entry);
+        self.entries.truncate(Self::CAPACITY);
+        Some(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, score: u32, attempts: u32) -> ScoreEntry {
+        ScoreEntry {
+            name: name.to_string(),
+            score,
+            attempts,
+        }
+    }
+
+    #[test]
+    fn test_submit() {
+        let mut leaderboard = Leaderboard::default();
+        assert_eq!(leaderboard.submit(entry("a", 100, 5)), Some(0));
+        assert_eq!(leaderboard.submit(entry("b", 300, 2)), Some(0));
+        assert_eq!(leaderboard.submit(entry("c", 100, 3)), Some(1));
+        let names: Vec<_> = leaderboard
+            .entries
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(names, ["b", "c", "a"]);
+
+        for score in 200..210 {
+            leaderboard.submit(entry("filler", score, 4));
+        }
+        assert_eq!(leaderboard.entries.len(), Leaderboard::CAPACITY);
+        assert_eq!(leaderboard.submit(entry("d", 50, 1)), None);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join(format!("libguess-scores-{}.txt", std::process::id()));
+        assert_eq!(Leaderboard::load(&path).unwrap(), Leaderboard::default());
+
+        let mut leaderboard = Leaderboard::default();
+        leaderboard.submit(entry("Ada Lovelace", 250, 3));
+        let outcome = GameOutcome::Won {
+            attempts: 4,
+            score: 120,
+        };
+        leaderboard.submit(ScoreEntry::from_outcome("Tab\tName", &outcome).unwrap());
+        leaderboard.save(&path).unwrap();
+
+        let loaded = Leaderboard::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, leaderboard);
+        assert_eq!(loaded.entries[1].name, "Tab Name");
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{Game, GameTrait, GuessResult, Profile};
+
+/// A player's run of games: their profile and the game in progress, kept together so a session
+/// can be quit and resumed later with its streak and totals intact.
+///
+/// With the `serde` feature the session can be saved and loaded. The game inside is saved as
+/// [`Game`] saves itself, so a resumed game keeps its secret, bounds, lives and seed but gets a
+/// freshly seeded random number generator.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Session {
+    pub profile: Profile,
+    pub game: Game,
+}
+
+impl Session {
+    /// Starts a session for `profile` with `game` as its first game.
+    pub fn new(profile: Profile, game: Game) -> Self {
+        Self { profile, game }
+    }
+
+    /// Plays `guess` in the current game, recording the game in the profile when it ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GuessResult, Profile, Session};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let game = Game {
+    ///     secret_number: 7,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// let mut session = Session::new(Profile::new("Ada"), game);
+    /// assert_eq!(session.play(7), GuessResult::Correct);
+    /// assert_eq!(session.profile.current_streak, 1);
+    /// ```
+    pub fn play(&mut self, guess: u32) -> GuessResult {
+        let was_over = self.game.is_over();
+        let result = self.game.play(guess);
+        if !was_over {
+            if let Some(outcome) = self.game.outcome() {
+                self.profile.record(&outcome);
+            }
+        }
+        result
+    }
+
+    /// Moves on to `game`. A current game that is still in progress is given up and recorded as a
+    /// loss, ending the streak.
+    pub fn next_game(&mut self, game: Game) {
+        if !self.game.is_over() {
+            let outcome = self.game.forfeit();
+            self.profile.record(&outcome);
+        }
+        self.game = game;
+    }
+
+    /// Reads a session saved as JSON by [`Session::to_writer`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails or the data is not a valid saved session.
+    #[cfg(feature = "serde")]
+    pub fn from_reader(reader: impl std::io::Read) -> serde_json::Result<Self> {
+        serde_json::from_reader(reader)
+    }
+
+    /// Saves the session as JSON, for example to a file before the app quits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    #[cfg(feature = "serde")]
+    pub fn to_writer(&self, writer: impl std::io::Write) -> serde_json::Result<()> {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GameConfig;
+
+    fn game(secret: u32, seed: u64) -> Game {
+        Game {
+            secret_number: secret,
+            ..Game::from_seed(seed, GameConfig::default())
+        }
+    }
+
+    #[test]
+    fn test_records_each_game_once() {
+        let mut session = Session::new(Profile::new("Ada"), game(5, 1));
+        session.play(5);
+        session.play(5);
+        assert_eq!(session.profile.games_won, 1);
+        assert_eq!(session.profile.current_streak, 1);
+
+        session.next_game(game(9, 2));
+        session.play(9);
+        assert_eq!(session.profile.current_streak, 2);
+
+        session.next_game(game(3, 3));
+        session.play(4);
+        session.next_game(game(3, 4));
+        assert_eq!(session.profile.games_played, 3);
+        assert_eq!(session.profile.current_streak, 0);
+        assert_eq!(session.profile.best_streak, 2);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_resume_round_trip() {
+        use std::io::Cursor;
+
+        let mut session = Session::new(Profile::new("Ada"), game(5, 1));
+        session.play(5);
+        session.next_game(game(9, 2));
+        session.play(9);
+        session.next_game(game(14, 3));
+        assert_eq!(session.play(10), GuessResult::TooLow);
+
+        let mut buffer = Cursor::new(Vec::new());
+        session.to_writer(&mut buffer).unwrap();
+        buffer.set_position(0);
+        let mut resumed = Session::from_reader(buffer).unwrap();
+
+        assert_eq!(resumed.profile, session.profile);
+        assert_eq!(resumed.game.public_state(), session.game.public_state());
+        assert_eq!(resumed.game.seed, Some(3));
+        assert_eq!(resumed.play(14), GuessResult::Correct);
+        assert_eq!(resumed.profile.current_streak, 3);
+        assert_eq!(resumed.profile.games_won, 3);
+    }
+}
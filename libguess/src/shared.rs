@@ -0,0 +1,74 @@
+use crate::{Game, GameState, GameTrait, GuessResult, PublicState};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// A cloneable handle to a game that can be played from several threads at once.
+///
+/// Every method takes the lock for the duration of a single call, so each guess is applied
+/// atomically and callers never hold the lock themselves.
+#[derive(Clone)]
+pub struct SharedGame(Arc<Mutex<Game>>);
+
+impl SharedGame {
+    /// Wraps a game so it can be shared between threads.
+    pub fn new(game: Game) -> Self {
+        Self(Arc::new(Mutex::new(game)))
+    }
+
+    /// Plays a guess against the shared game. See [`GameTrait::play`].
+    pub fn play(&self, guess: u32) -> GuessResult {
+        self.lock().play(guess)
+    }
+
+    /// Returns whether the shared game is in progress, won or lost.
+    pub fn state(&self) -> GameState {
+        self.lock().state()
+    }
+
+    /// Returns the player-visible snapshot of the shared game.
+    pub fn public_state(&self) -> PublicState {
+        self.lock().public_state()
+    }
+
+    /// Locks the game, carrying on past a panic in another thread since every update to the game
+    /// leaves it in a consistent state.
+    fn lock(&self) -> MutexGuard<'_, Game> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::thread;
+
+    #[test]
+    fn test_concurrent_play() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let shared = SharedGame::new(Game {
+            secret_number: 1000,
+            ..Game::new(Some(1), Some(1000), Some(100), &mut rng)
+        });
+
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    for guess in 1..=5 {
+                        assert_eq!(shared.play(i * 10 + guess), GuessResult::TooLow);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let state = shared.public_state();
+        assert_eq!(state.attempts, 20);
+        assert_eq!(state.lives, 80);
+        assert_eq!(state.low, 36);
+        assert_eq!(shared.state(), GameState::InProgress);
+    }
+}
@@ -0,0 +1,35 @@
+use crate::{compare, GuessResult};
+
+/// Decides the feedback a guess receives.
+///
+/// A game reports whatever its strategy returns, while its bounds keep tracking the true order of
+/// the guess and the secret. Strategies must only return `Correct` when the guess is the secret.
+pub trait CompareStrategy {
+    /// Returns the feedback for `guess` against `secret`.
+    fn compare(&self, guess: u32, secret: u32) -> GuessResult;
+}
+
+/// The standard feedback: whether the guess is too high or too low. See [`compare`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HighLow;
+
+impl CompareStrategy for HighLow {
+    fn compare(&self, guess: u32, secret: u32) -> GuessResult {
+        compare(guess, secret)
+    }
+}
+
+/// Feedback as if the range were flipped end to end: a guess above the secret is reported as too
+/// low, and one below it as too high.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Reversed;
+
+impl CompareStrategy for Reversed {
+    fn compare(&self, guess: u32, secret: u32) -> GuessResult {
+        match compare(guess, secret) {
+            GuessResult::TooHigh => GuessResult::TooLow,
+            GuessResult::TooLow => GuessResult::TooHigh,
+            result => result,
+        }
+    }
+}
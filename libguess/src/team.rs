@@ -0,0 +1,155 @@
+use crate::{Game, GameState, GameTrait, GuessResult};
+
+/// A cooperative game where several players take turns guessing the same secret, drawing on the
+/// game's lives as a shared pool.
+///
+/// Players are numbered from 0 and play in order, starting with player 0.
+pub struct TeamGame {
+    game: Game,
+    players: usize,
+    turn: usize,
+    winner: Option<usize>,
+}
+
+impl TeamGame {
+    /// Sets up `game` for a team of `players`, treating 0 players as 1.
+    pub fn new(game: Game, players: usize) -> Self {
+        Self {
+            game,
+            players: players.max(1),
+            turn: 0,
+            winner: None,
+        }
+    }
+
+    /// Plays `guess` for `player`, costing the whole team a life if it is wrong.
+    ///
+    /// Returns `None` without playing anything if it is not `player`'s turn. A guess that could
+    /// not be played, because the lives have run out or the number is excluded, does not use up
+    /// the turn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GuessResult, TeamGame};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let game = Game {
+    ///     secret_number: 7,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// let mut team = TeamGame::new(game, 2);
+    /// assert_eq!(team.play(0, 5), Some(GuessResult::TooLow));
+    /// assert_eq!(team.play(0, 8), None);
+    /// assert_eq!(team.play(1, 7), Some(GuessResult::Correct));
+    /// assert_eq!(team.winner(), Some(1));
+    /// ```
+    pub fn play(&mut self, player: usize, guess: u32) -> Option<GuessResult> {
+        if player != self.turn {
+            return None;
+        }
+
+        let result = self.game.play(guess);
+        match result {
+            GuessResult::NoMoreLives | GuessResult::Excluded => return Some(result),
+            GuessResult::Correct if self.winner.is_none() => self.winner = Some(player),
+            _ => {}
+        }
+        self.turn = (self.turn + 1) % self.players;
+        Some(result)
+    }
+
+    /// Returns whose turn it is.
+    pub fn current_player(&self) -> usize {
+        self.turn
+    }
+
+    /// Returns the player whose guess found the secret, or `None` if nobody has yet.
+    pub fn winner(&self) -> Option<usize> {
+        self.winner
+    }
+
+    /// Returns the lives left in the team's shared pool.
+    pub fn lives(&self) -> u32 {
+        self.game.lives()
+    }
+
+    /// Returns whether the game is in progress, won or lost.
+    pub fn state(&self) -> GameState {
+        self.game.state()
+    }
+
+    /// Returns the game the team is playing.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn pair(secret: u32, lives: u32) -> TeamGame {
+        let mut rng = StdRng::from_seed(Default::default());
+        let game = Game {
+            secret_number: secret,
+            ..Game::new(Some(1), Some(100), Some(lives), &mut rng)
+        };
+        TeamGame::new(game, 2)
+    }
+
+    #[test]
+    fn test_shared_pool() {
+        let mut team = pair(70, 3);
+        assert_eq!(team.play(0, 50), Some(GuessResult::TooLow));
+        assert_eq!(team.current_player(), 1);
+        assert_eq!(team.play(1, 90), Some(GuessResult::TooHigh));
+        assert_eq!(team.lives(), 1);
+        assert_eq!(team.play(1, 60), None);
+        assert_eq!(team.play(0, 60), Some(GuessResult::TooLow));
+        assert_eq!(team.lives(), 0);
+        assert_eq!(team.state(), GameState::Lost);
+        assert_eq!(team.winner(), None);
+
+        assert_eq!(team.play(1, 70), Some(GuessResult::NoMoreLives));
+        assert_eq!(team.current_player(), 1);
+    }
+
+    #[test]
+    fn test_winner() {
+        let mut team = pair(70, 10);
+        for (player, guess) in [(0, 50), (1, 75), (0, 62), (1, 68), (0, 70)] {
+            team.play(player, guess).unwrap();
+        }
+        assert_eq!(team.winner(), Some(0));
+        assert_eq!(team.state(), GameState::Won);
+        assert_eq!(team.game().attempts, 5);
+        assert_eq!(team.lives(), 6);
+
+        let mut team = pair(70, 10);
+        team.play(0, 50);
+        team.play(1, 70);
+        assert_eq!(team.winner(), Some(1));
+    }
+
+    #[test]
+    fn test_excluded_keeps_turn() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let game = Game {
+            secret_number: 7,
+            excluded: vec![3],
+            ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+        };
+        let mut team = TeamGame::new(game, 3);
+        assert_eq!(team.play(0, 3), Some(GuessResult::Excluded));
+        assert_eq!(team.current_player(), 0);
+        team.play(0, 4);
+        team.play(1, 5);
+        team.play(2, 6);
+        assert_eq!(team.current_player(), 0);
+    }
+}
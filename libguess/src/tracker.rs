@@ -0,0 +1,157 @@
+use crate::{midpoint, quartile_bounds, GuessResult};
+
+/// A model of a game's search space that does not know the secret.
+///
+/// Made with [`Game::as_tracker`](crate::Game::as_tracker), it lets a UI component or assistant
+/// reason about which numbers are still possible without being able to peek at the answer. Feed
+/// it the results the game gives with [`Tracker::apply_feedback`] to keep it in step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tracker {
+    min_num: u32,
+    max_num: u32,
+    low: u32,
+    high: u32,
+    excluded: Vec<u32>,
+}
+
+impl Tracker {
+    pub(crate) fn new(min_num: u32, max_num: u32, low: u32, high: u32, excluded: &[u32]) -> Self {
+        Self {
+            min_num,
+            max_num,
+            low,
+            high,
+            excluded: excluded.to_vec(),
+        }
+    }
+
+    /// Returns the full range of the game, as `(min_num, max_num)`.
+    pub fn range(&self) -> (u32, u32) {
+        (self.min_num, self.max_num)
+    }
+
+    /// Returns the range the secret number is still known to lie in, as `(low, high)`.
+    pub fn bounds(&self) -> (u32, u32) {
+        (self.low, self.high)
+    }
+
+    /// Returns how many numbers are still possible given the current bounds.
+    ///
+    /// Excluded numbers inside the bounds are not counted.
+    pub fn possible_count(&self) -> u64 {
+        let excluded = self
+            .excluded
+            .iter()
+            .filter(|n| (self.low..=self.high).contains(n))
+            .count() as u64;
+        u64::from(self.high) - u64::from(self.low) + 1 - excluded
+    }
+
+    /// Returns the guess a perfect player would make next: the middle of the current bounds.
+    pub fn suggest_guess(&self) -> u32 {
+        midpoint(self.low, self.high)
+    }
+
+    /// Narrows the bounds using the result the game gave for `guess`.
+    ///
+    /// `NoMoreLives`, `Excluded` and `MercyLoss` tell nothing about the secret and are ignored.
+    ///
+    /// The tracker only sees the answers, so it takes them in the standard high-low order and
+    /// treats a correct guess as the end of the search. Under the [`Reversed`](crate::Reversed)
+    /// strategy it narrows the wrong way, and in bonus mode it stays on the number just found
+    /// while the game starts a new round over the whole range. In either case take a fresh
+    /// tracker from the game to get back in step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libguess::{Game, GameTrait};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::from_seed(Default::default());
+    /// let mut game = Game {
+    ///     secret_number: 7,
+    ///     ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+    /// };
+    /// let mut tracker = game.as_tracker();
+    /// let result = game.play(4);
+    /// tracker.apply_feedback(4, result);
+    /// assert_eq!(tracker.bounds(), (5, 10));
+    /// ```
+    pub fn apply_feedback(&mut self, guess: u32, result: GuessResult) {
+        match result {
+            GuessResult::Correct | GuessResult::Draw => {
+                self.low = guess;
+                self.high = guess;
+            }
+            GuessResult::TooHigh => self.high = self.high.min(guess.saturating_sub(1)),
+            GuessResult::TooLow => self.low = self.low.max(guess.saturating_add(1)),
+            GuessResult::Quartile(quarter) => {
+                (self.low, self.high) = quartile_bounds(self.low, self.high, quarter, guess);
+            }
+            GuessResult::NoMoreLives | GuessResult::Excluded | GuessResult::MercyLoss => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Game, GameTrait, GuessResult, Reversed};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_tracks_game_bounds() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game::new_excluding(Some(1), Some(100), Some(10), &[33], &mut rng).unwrap();
+        game.secret_number = 62;
+        let mut tracker = game.as_tracker();
+        assert_eq!(tracker.possible_count(), game.possible_count());
+
+        for guess in [50, 33, 80, 60, 70, 65, 62] {
+            let result = game.play(guess);
+            tracker.apply_feedback(guess, result);
+            assert_eq!(tracker.bounds(), game.bounds());
+            assert_eq!(tracker.possible_count(), game.possible_count());
+            assert_eq!(tracker.suggest_guess(), game.suggest_guess());
+        }
+        assert_eq!(tracker.bounds(), (62, 62));
+        assert_eq!(tracker.range(), (1, 100));
+    }
+
+    #[test]
+    fn test_reversed_strategy_not_followed() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 30,
+            strategy: Box::new(Reversed),
+            ..Game::new(Some(1), Some(100), Some(10), &mut rng)
+        };
+        let mut tracker = game.as_tracker();
+        let result = game.play(50);
+        assert_eq!(result, GuessResult::TooLow);
+        tracker.apply_feedback(50, result);
+        assert_eq!(game.bounds(), (1, 49));
+        assert_eq!(tracker.bounds(), (51, 100));
+        assert_eq!(game.as_tracker().bounds(), game.bounds());
+    }
+
+    #[test]
+    fn test_bonus_round_not_followed() {
+        let mut rng = StdRng::from_seed(Default::default());
+        let mut game = Game {
+            secret_number: 6,
+            bonus_mode: true,
+            ..Game::new(Some(1), Some(10), Some(5), &mut rng)
+        };
+        let mut tracker = game.as_tracker();
+        let result = game.play(6);
+        assert_eq!(result, GuessResult::Correct);
+        tracker.apply_feedback(6, result);
+        assert_eq!(game.bounds(), (1, 10));
+        assert_eq!(tracker.bounds(), (6, 6));
+        assert_eq!(game.as_tracker().bounds(), game.bounds());
+    }
+}
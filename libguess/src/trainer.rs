@@ -0,0 +1,130 @@
+use crate::{min_lives_to_guarantee, optimal_guesses, GameConfig, GameOutcome};
+
+/// Suggests the next practice game from the games a player has already played, aiming at their
+/// weak spot without making it unwinnable.
+///
+/// Games are grouped by how many guesses their range takes a perfect player. The weak spot is the
+/// easiest group the player wins less than half of; the recommended range sits halfway, on a log
+/// scale, between the largest range they do win and that weak spot. A player weak everywhere gets
+/// their weak range with extra lives instead. With no weak spot the range grows fivefold from the
+/// largest played, and with no games at all this is the default config. The lives always cover a
+/// perfect game plus a little slack.
+///
+/// # Examples
+///
+/// ```
+/// use libguess::{recommend_next_config, GameConfig, GameOutcome};
+///
+/// let small = GameConfig::default();
+/// let huge = GameConfig { max_num: 10_000, ..GameConfig::default() };
+/// let won = GameOutcome::Won { attempts: 4, score: 200 };
+/// let lost = GameOutcome::Lost { attempts: 10, secret: 1234 };
+/// let next = recommend_next_config(&[(small, won), (huge, lost)]);
+/// assert!(next.max_num > small.max_num && next.max_num < huge.max_num);
+/// ```
+pub fn recommend_next_config(played: &[(GameConfig, GameOutcome)]) -> GameConfig {
+    let size = |config: &GameConfig| u64::from(config.max_num.saturating_sub(config.min_num)) + 1;
+
+    let mut levels: Vec<(u32, u32, u32)> = Vec::new();
+    for (config, outcome) in played {
+        let level = optimal_guesses(size(config));
+        let won = u32::from(matches!(outcome, GameOutcome::Won { .. }));
+        match levels.iter_mut().find(|(l, ..)| *l == level) {
+            Some((_, games, wins)) => {
+                *games += 1;
+                *wins += won;
+            }
+            None => levels.push((level, 1, won)),
+        }
+    }
+    let weak_level = levels
+        .iter()
+        .filter(|(_, games, wins)| wins * 2 < *games)
+        .map(|(level, ..)| *level)
+        .min();
+    let largest_won = |below: u32| {
+        played
+            .iter()
+            .filter(|(config, outcome)| {
+                matches!(outcome, GameOutcome::Won { .. }) && optimal_guesses(size(config)) < below
+            })
+            .map(|(config, _)| size(config))
+            .max()
+    };
+
+    let (range, slack) = match weak_level {
+        Some(level) => {
+            let weak = played
+                .iter()
+                .map(|(config, _)| size(config))
+                .filter(|&size| optimal_guesses(size) == level)
+                .min()
+                .unwrap_or(1);
+            match largest_won(level) {
+                Some(strong) => (((strong as f64) * (weak as f64)).sqrt().round() as u64, 1),
+                None => (weak, 3),
+            }
+        }
+        None => match largest_won(u32::MAX) {
+            Some(strong) => (strong.saturating_mul(5), 1),
+            None => return GameConfig::default(),
+        },
+    };
+
+    let max_num = u32::try_from(range.max(2)).unwrap_or(u32::MAX);
+    GameConfig {
+        min_num: 1,
+        max_num,
+        lives: min_lives_to_guarantee(1, max_num) + slack,
+        ..GameConfig::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn games(max_num: u32, wins: u32, losses: u32) -> Vec<(GameConfig, GameOutcome)> {
+        let config = GameConfig {
+            min_num: 1,
+            max_num,
+            ..GameConfig::default()
+        };
+        let won = GameOutcome::Won {
+            attempts: 5,
+            score: 150,
+        };
+        let lost = GameOutcome::Lost {
+            attempts: 10,
+            secret: 1,
+        };
+        std::iter::repeat((config, won))
+            .take(wins as usize)
+            .chain(std::iter::repeat((config, lost)).take(losses as usize))
+            .collect()
+    }
+
+    #[test]
+    fn test_weak_on_large_ranges() {
+        let played = [games(20, 5, 0), games(100, 3, 1), games(10_000, 1, 4)].concat();
+        let next = recommend_next_config(&played);
+        assert_eq!((next.min_num, next.max_num), (1, 1000));
+        assert_eq!(next.lives, min_lives_to_guarantee(1, 1000) + 1);
+    }
+
+    #[test]
+    fn test_weak_everywhere() {
+        let next = recommend_next_config(&games(50, 1, 3));
+        assert_eq!((next.min_num, next.max_num), (1, 50));
+        assert_eq!(next.lives, min_lives_to_guarantee(1, 50) + 3);
+    }
+
+    #[test]
+    fn test_no_weak_spot() {
+        assert_eq!(recommend_next_config(&[]), GameConfig::default());
+
+        let next = recommend_next_config(&[games(20, 3, 0), games(100, 2, 1)].concat());
+        assert_eq!(next.max_num, 500);
+        assert_eq!(next.lives, min_lives_to_guarantee(1, 500) + 1);
+    }
+}